@@ -0,0 +1,353 @@
+// ASN.1 DER 编解码（GM/T 0009 等结构使用的最小子集）
+
+mod pem;
+
+pub(crate) use pem::{pem_decode, pem_decode_all, pem_encode};
+
+pub(crate) const TAG_BOOLEAN: u8 = 0x01;
+pub(crate) const TAG_INTEGER: u8 = 0x02;
+pub(crate) const TAG_BIT_STRING: u8 = 0x03;
+pub(crate) const TAG_OCTET_STRING: u8 = 0x04;
+pub(crate) const TAG_NULL: u8 = 0x05;
+pub(crate) const TAG_OID: u8 = 0x06;
+pub(crate) const TAG_ENUMERATED: u8 = 0x0A;
+pub(crate) const TAG_UTF8_STRING: u8 = 0x0C;
+pub(crate) const TAG_PRINTABLE_STRING: u8 = 0x13;
+pub(crate) const TAG_TELETEX_STRING: u8 = 0x14;
+pub(crate) const TAG_IA5_STRING: u8 = 0x16;
+pub(crate) const TAG_UTC_TIME: u8 = 0x17;
+pub(crate) const TAG_GENERALIZED_TIME: u8 = 0x18;
+pub(crate) const TAG_BMP_STRING: u8 = 0x1E;
+pub(crate) const TAG_SEQUENCE: u8 = 0x30;
+pub(crate) const TAG_SET: u8 = 0x31;
+/// 上下文相关的构造类型tag，[n] 为 TAG_CONTEXT | n
+pub(crate) const TAG_CONTEXT: u8 = 0xA0;
+/// 上下文相关的原始类型tag，用于IMPLICIT标记的原始类型
+pub(crate) const TAG_CONTEXT_PRIMITIVE: u8 = 0x80;
+
+/// id-ecPublicKey 1.2.840.10045.2.1
+pub(crate) const OID_EC_PUBLIC_KEY: &[u8] = &[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01];
+/// SM2曲线 1.2.156.10197.1.301
+pub(crate) const OID_SM2: &[u8] = &[0x2A, 0x81, 0x1C, 0xCF, 0x55, 0x01, 0x82, 0x2D];
+/// SM2签名算法（SM3摘要） 1.2.156.10197.1.501
+pub(crate) const OID_SM2_WITH_SM3: &[u8] = &[0x2A, 0x81, 0x1C, 0xCF, 0x55, 0x01, 0x83, 0x75];
+/// PKCS#9 extensionRequest 1.2.840.113549.1.9.14
+pub(crate) const OID_EXTENSION_REQUEST: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x0E];
+/// PBES2 1.2.840.113549.1.5.13
+pub(crate) const OID_PBES2: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x05, 0x0D];
+/// PBKDF2 1.2.840.113549.1.5.12
+pub(crate) const OID_PBKDF2: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x05, 0x0C];
+/// HMAC-SM3 1.2.156.10197.1.401.2（GmSSL）
+pub(crate) const OID_HMAC_SM3: &[u8] = &[0x2A, 0x81, 0x1C, 0xCF, 0x55, 0x01, 0x83, 0x11, 0x02];
+/// hmacWithSM3 1.2.156.10197.1.401.3.1（OpenSSL）
+pub(crate) const OID_HMAC_SM3_OPENSSL: &[u8] = &[0x2A, 0x81, 0x1C, 0xCF, 0x55, 0x01, 0x83, 0x11, 0x03, 0x01];
+/// SM4-CBC 1.2.156.10197.1.104.2
+pub(crate) const OID_SM4_CBC: &[u8] = &[0x2A, 0x81, 0x1C, 0xCF, 0x55, 0x01, 0x68, 0x02];
+
+/// DER读取器，按顺序解析TLV，所有方法在格式错误时返回None
+pub(crate) struct DerReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// 读取任意TLV，返回 (tag, value)
+    pub(crate) fn read_any(&mut self) -> Option<(u8, &'a [u8])> {
+        let (&tag, rest) = self.data.split_first()?;
+        // 不支持多字节tag
+        if tag & 0x1F == 0x1F {
+            return None;
+        }
+        let (&first, mut rest) = rest.split_first()?;
+        let len = if first < 0x80 {
+            first as usize
+        } else {
+            // 长格式：禁止不定长，长度字节须最短
+            let count = (first & 0x7F) as usize;
+            if count == 0 || count > 4 || rest.len() < count || rest[0] == 0 {
+                return None;
+            }
+            let mut len = 0usize;
+            for &b in &rest[..count] {
+                len = (len << 8) | b as usize;
+            }
+            if len < 0x80 {
+                return None;
+            }
+            rest = &rest[count..];
+            len
+        };
+        if rest.len() < len {
+            return None;
+        }
+        let (value, rest) = rest.split_at(len);
+        self.data = rest;
+        Some((tag, value))
+    }
+
+    /// 读取指定tag的TLV，返回value
+    pub(crate) fn read(&mut self, tag: u8) -> Option<&'a [u8]> {
+        let (actual, value) = self.read_any()?;
+        if actual == tag { Some(value) } else { None }
+    }
+
+    /// 下一个TLV的tag为指定值时读取并返回value，否则不消耗数据并返回None
+    pub(crate) fn read_optional(&mut self, tag: u8) -> Option<&'a [u8]> {
+        if self.data.first() == Some(&tag) { self.read(tag) } else { None }
+    }
+
+    /// 读取指定tag的TLV，返回包括tag与长度在内的完整编码
+    pub(crate) fn read_raw(&mut self, tag: u8) -> Option<&'a [u8]> {
+        let start = self.data;
+        self.read(tag)?;
+        Some(&start[..start.len() - self.data.len()])
+    }
+
+    /// 下一个TLV的tag，数据已读完时返回None
+    pub(crate) fn peek_tag(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// 读取SEQUENCE并返回其内容的读取器
+    pub(crate) fn read_sequence(&mut self) -> Option<DerReader<'a>> {
+        self.read(TAG_SEQUENCE).map(DerReader::new)
+    }
+
+    /// 读取非负INTEGER，返回去掉前导0x00后的大端数值
+    ///
+    /// 拒绝非最短编码与负数
+    pub(crate) fn read_unsigned_integer(&mut self) -> Option<&'a [u8]> {
+        let value = self.read(TAG_INTEGER)?;
+        match value {
+            [] => None,
+            [b, ..] if b & 0x80 != 0 => None,
+            [0, next, ..] if next & 0x80 == 0 => None,
+            [0, rest @ ..] if !rest.is_empty() => Some(rest),
+            _ => Some(value),
+        }
+    }
+
+    /// 读取不超过32字节的非负INTEGER并左补零为32字节
+    pub(crate) fn read_uint256(&mut self) -> Option<[u8; 32]> {
+        let value = self.read_unsigned_integer()?;
+        if value.len() > 32 {
+            return None;
+        }
+        let mut out = [0u8; 32];
+        out[32 - value.len()..].copy_from_slice(value);
+        Some(out)
+    }
+
+    /// 读取未用位数为0的BIT STRING，返回其字节内容
+    pub(crate) fn read_bit_string(&mut self) -> Option<&'a [u8]> {
+        match self.read(TAG_BIT_STRING)? {
+            [0, rest @ ..] => Some(rest),
+            _ => None,
+        }
+    }
+
+    /// 读取OBJECT IDENTIFIER并要求等于指定值（均为编码后的内容字节）
+    pub(crate) fn expect_oid(&mut self, oid: &[u8]) -> Option<()> {
+        if self.read(TAG_OID)? == oid { Some(()) } else { None }
+    }
+
+    /// 读取BOOLEAN，DER要求TRUE编码为0xFF
+    pub(crate) fn read_boolean(&mut self) -> Option<bool> {
+        match self.read(TAG_BOOLEAN)? {
+            [0x00] => Some(false),
+            [0xFF] => Some(true),
+            _ => None,
+        }
+    }
+
+    /// 读取UTCTime或GeneralizedTime，返回Unix时间戳（秒）
+    ///
+    /// 只接受DER规定的UTC形式：YYMMDDHHMMSSZ 与 YYYYMMDDHHMMSSZ，UTCTime年份按RFC 5280映射到1950-2049
+    pub(crate) fn read_time(&mut self) -> Option<i64> {
+        let (tag, value) = self.read_any()?;
+        let (year, rest) = match (tag, value.len()) {
+            (TAG_UTC_TIME, 13) => {
+                let yy = parse_digits(&value[..2])?;
+                (if yy < 50 { 2000 + yy } else { 1900 + yy }, &value[2..])
+            }
+            (TAG_GENERALIZED_TIME, 15) => (parse_digits(&value[..4])?, &value[4..]),
+            _ => return None,
+        };
+        if rest[10] != b'Z' {
+            return None;
+        }
+        let month = parse_digits(&rest[0..2])?;
+        let day = parse_digits(&rest[2..4])?;
+        let hour = parse_digits(&rest[4..6])?;
+        let minute = parse_digits(&rest[6..8])?;
+        let second = parse_digits(&rest[8..10])?;
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) || hour > 23 || minute > 59 || second > 59
+        {
+            return None;
+        }
+        Some(days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second)
+    }
+
+    /// 要求所有数据都已读完
+    pub(crate) fn finish(self) -> Option<()> {
+        if self.data.is_empty() { Some(()) } else { None }
+    }
+}
+
+/// 编码TLV
+pub(crate) fn encode_tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(value.len() + 6);
+    out.push(tag);
+    let len = value.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes = (len as u32).to_be_bytes();
+        let skip = bytes.iter().take_while(|&&b| b == 0).count();
+        out.push(0x80 | (4 - skip) as u8);
+        out.extend_from_slice(&bytes[skip..]);
+    }
+    out.extend_from_slice(value);
+    out
+}
+
+/// 编码SEQUENCE，内容为若干已编码的TLV依次拼接
+pub(crate) fn encode_sequence(items: &[&[u8]]) -> Vec<u8> {
+    encode_tlv(TAG_SEQUENCE, &items.concat())
+}
+
+/// 把大端无符号整数编码为最短的非负INTEGER
+pub(crate) fn encode_unsigned_integer(value: &[u8]) -> Vec<u8> {
+    let skip = value.iter().take_while(|&&b| b == 0).count();
+    let trimmed = &value[skip..];
+    let mut content = Vec::with_capacity(trimmed.len() + 1);
+    if trimmed.first().is_none_or(|&b| b & 0x80 != 0) {
+        content.push(0);
+    }
+    content.extend_from_slice(trimmed);
+    encode_tlv(TAG_INTEGER, &content)
+}
+
+/// 编码未用位数为0的BIT STRING
+pub(crate) fn encode_bit_string(value: &[u8]) -> Vec<u8> {
+    let mut content = Vec::with_capacity(value.len() + 1);
+    content.push(0);
+    content.extend_from_slice(value);
+    encode_tlv(TAG_BIT_STRING, &content)
+}
+
+/// 编码OBJECT IDENTIFIER，参数为编码后的内容字节
+pub(crate) fn encode_oid(oid: &[u8]) -> Vec<u8> {
+    encode_tlv(TAG_OID, oid)
+}
+
+/// 编码时间：2050年之前用UTCTime，之后用GeneralizedTime（RFC 5280 4.1.2.5）
+pub(crate) fn encode_time(unix: i64) -> Vec<u8> {
+    let (year, month, day) = civil_from_days(unix.div_euclid(86400));
+    let seconds = unix.rem_euclid(86400);
+    let (hour, minute, second) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if (1950..2050).contains(&year) {
+        let text = format!("{:02}{:02}{:02}{:02}{:02}{:02}Z", year % 100, month, day, hour, minute, second);
+        encode_tlv(TAG_UTC_TIME, text.as_bytes())
+    } else {
+        let text = format!("{:04}{:02}{:02}{:02}{:02}{:02}Z", year, month, day, hour, minute, second);
+        encode_tlv(TAG_GENERALIZED_TIME, text.as_bytes())
+    }
+}
+
+/// 编码BOOLEAN
+pub(crate) fn encode_boolean(value: bool) -> Vec<u8> {
+    encode_tlv(TAG_BOOLEAN, &[if value { 0xFF } else { 0x00 }])
+}
+
+/// 把点分十进制OID转换为内容字节，格式非法时返回None
+pub(crate) fn oid_from_string(oid: &str) -> Option<Vec<u8>> {
+    let arcs: Vec<u64> = oid.split('.').map(|arc| arc.parse().ok()).collect::<Option<_>>()?;
+    if arcs.len() < 2 || arcs[0] > 2 || (arcs[0] < 2 && arcs[1] >= 40) {
+        return None;
+    }
+    let mut out = Vec::new();
+    let first = arcs[0].checked_mul(40)?.checked_add(arcs[1])?;
+    for arc in std::iter::once(first).chain(arcs[2..].iter().copied()) {
+        // 按7位分组，高位在前，除最后一组外最高位置1
+        let groups = (64 - arc.leading_zeros()).div_ceil(7).max(1);
+        for i in (0..groups).rev() {
+            let byte = ((arc >> (7 * i)) & 0x7F) as u8;
+            out.push(if i > 0 { byte | 0x80 } else { byte });
+        }
+    }
+    Some(out)
+}
+
+/// 把OID内容字节转换为点分十进制，编码非法时返回None
+pub(crate) fn oid_to_string(oid: &[u8]) -> Option<String> {
+    let mut arcs = Vec::new();
+    let mut value = 0u64;
+    for (i, &b) in oid.iter().enumerate() {
+        // 每个子标识须最短编码且不超过64位
+        if value == 0 && b == 0x80 || value >> 57 != 0 {
+            return None;
+        }
+        value = (value << 7) | (b & 0x7F) as u64;
+        if b & 0x80 == 0 {
+            if arcs.is_empty() {
+                let first = (value / 40).min(2);
+                arcs.push(first);
+                arcs.push(value - 40 * first);
+            } else {
+                arcs.push(value);
+            }
+            value = 0;
+        } else if i == oid.len() - 1 {
+            return None;
+        }
+    }
+    if arcs.is_empty() {
+        return None;
+    }
+    Some(arcs.iter().map(|a| a.to_string()).collect::<Vec<_>>().join("."))
+}
+
+/// 解析若干位十进制数字
+fn parse_digits(digits: &[u8]) -> Option<i64> {
+    digits.iter().try_fold(0i64, |acc, &d| d.is_ascii_digit().then(|| acc * 10 + (d - b'0') as i64))
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 公历日期到1970-01-01的天数（Howard Hinnant 的 days_from_civil 算法）
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// days_from_civil 的逆运算，返回 (年, 月, 日)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    (if month <= 2 { yoe + era * 400 + 1 } else { yoe + era * 400 }, month, day)
+}
@@ -0,0 +1,97 @@
+// PEM（RFC 7468）编解码：-----BEGIN label----- / Base64正文 / -----END label-----
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// 编码为PEM，正文每行64个字符，以换行结尾
+pub(crate) fn pem_encode(label: &str, der: &[u8]) -> String {
+    let body = base64_encode(der);
+    let mut out = String::with_capacity(body.len() + body.len() / 64 + 2 * label.len() + 32);
+    out.push_str("-----BEGIN ");
+    out.push_str(label);
+    out.push_str("-----\n");
+    for line in body.as_bytes().chunks(64) {
+        // Base64字母表均为ASCII
+        out.push_str(std::str::from_utf8(line).unwrap());
+        out.push('\n');
+    }
+    out.push_str("-----END ");
+    out.push_str(label);
+    out.push_str("-----\n");
+    out
+}
+
+/// 解析文本中的第一个PEM块，返回 (label, DER)
+///
+/// 块前后的其他文本被忽略；缺少结束行、标签不一致或Base64非法时返回None
+pub(crate) fn pem_decode(text: &str) -> Option<(String, Vec<u8>)> {
+    let start = text.find("-----BEGIN ")?;
+    let (label, der, _) = decode_block(&text[start..])?;
+    Some((label, der))
+}
+
+/// 按顺序解析文本中的全部PEM块，任一块非法时返回None，没有PEM块时返回空列表
+pub(crate) fn pem_decode_all(mut text: &str) -> Option<Vec<(String, Vec<u8>)>> {
+    let mut blocks = Vec::new();
+    while let Some(start) = text.find("-----BEGIN ") {
+        let (label, der, rest) = decode_block(&text[start..])?;
+        blocks.push((label, der));
+        text = rest;
+    }
+    Some(blocks)
+}
+
+/// 解析以BEGIN行开头的PEM块，返回 (label, DER, 块之后的文本)
+fn decode_block(text: &str) -> Option<(String, Vec<u8>, &str)> {
+    let rest = text.strip_prefix("-----BEGIN ")?;
+    let (label, rest) = rest.split_once("-----")?;
+    if label.contains('\n') {
+        return None;
+    }
+    let end_line = format!("-----END {}-----", label);
+    let (body, rest) = rest.split_once(&end_line)?;
+    Some((label.to_string(), base64_decode(body)?, rest))
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3F] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// 解码Base64，忽略空白字符，要求填充正确且末尾未用位为0
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let chars: Vec<u8> = text.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if !chars.len().is_multiple_of(4) {
+        return None;
+    }
+    let mut out = Vec::with_capacity(chars.len() / 4 * 3);
+    for (index, chunk) in chars.chunks(4).enumerate() {
+        let last = index == chars.len() / 4 - 1;
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && !last) {
+            return None;
+        }
+        let mut n = 0u32;
+        for &c in &chunk[..4 - padding] {
+            let value = ALPHABET.iter().position(|&a| a == c)? as u32;
+            n = n << 6 | value;
+        }
+        n <<= 6 * padding as u32;
+        let bytes = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+        if bytes[3 - padding..].iter().any(|&b| b != 0) {
+            return None;
+        }
+        out.extend_from_slice(&bytes[..3 - padding]);
+    }
+    Some(out)
+}
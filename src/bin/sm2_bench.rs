@@ -1,32 +1,70 @@
-// SM2 性能基准测试
+// SM2 性能基准测试，与 RustCrypto sm2 对比（请使用 --release 运行）
 
-use gm_sdk::sm2::{sm2_sign, sm2_verify, sm2_generate_keypair};
-use std::time::Instant;
+use gm_sdk::sm2::{SM2_DEFAULT_ID, sm2_derive_public_key, sm2_generate_keypair, sm2_sign, sm2_verify};
+use sm2::dsa::signature::{Signer, Verifier};
+use sm2::dsa::{Signature, SigningKey, VerifyingKey};
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 200;
+
+/// 重复执行并返回每秒操作数
+fn ops_per_second(mut f: impl FnMut()) -> f64 {
+    // 预热，同时触发预计算表的构建
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed: Duration = start.elapsed();
+    ITERATIONS as f64 / elapsed.as_secs_f64()
+}
+
+fn report(name: &str, ours: f64, theirs: f64) {
+    println!("{:<8} gm-sdk: {:>10.0} 次/秒    RustCrypto sm2: {:>10.0} 次/秒    比值: {:.2}", name, ours, theirs, ours / theirs);
+}
 
 fn main() {
     println!("=== SM2 性能基准测试 ===");
-    
+
     // 生成密钥对
     println!("生成密钥对...");
     let (private_key, public_key) = sm2_generate_keypair();
-    
+    let distid = std::str::from_utf8(SM2_DEFAULT_ID).unwrap();
+    let secret = sm2::SecretKey::from_slice(&private_key).unwrap();
+    let signing_key = SigningKey::new(distid, &secret).unwrap();
+    let verifying_key = VerifyingKey::new(distid, secret.public_key()).unwrap();
+
     // 测试消息
     let message = b"Hello, SM2! This is a test message for performance benchmarking.";
-    
-    // 测试签名性能
-    println!("\n测试签名性能...");
-    let start = Instant::now();
     let signature = sm2_sign(&private_key, message);
-    let duration = start.elapsed();
-    println!("签名耗时: {:?}", duration);
-    
-    // 测试验签性能
-    println!("\n测试验签性能...");
-    let start = Instant::now();
-    let result = sm2_verify(&public_key, message, &signature);
-    let duration = start.elapsed();
-    println!("验签耗时: {:?}", duration);
-    println!("验签结果: {:?}", result);
-    
+    let their_signature: Signature = signing_key.sign(message);
+    assert!(sm2_verify(&public_key, message, &signature));
+
+    println!("\n每项执行 {} 次\n", ITERATIONS);
+
+    let ours = ops_per_second(|| {
+        sm2_derive_public_key(&private_key).unwrap();
+    });
+    let theirs = ops_per_second(|| {
+        sm2::SecretKey::from_slice(&private_key).unwrap().public_key();
+    });
+    report("公钥", ours, theirs);
+
+    let ours = ops_per_second(|| {
+        sm2_sign(&private_key, message);
+    });
+    let theirs = ops_per_second(|| {
+        let _: Signature = signing_key.sign(message);
+    });
+    report("签名", ours, theirs);
+
+    let ours = ops_per_second(|| {
+        assert!(sm2_verify(&public_key, message, &signature));
+    });
+    let theirs = ops_per_second(|| {
+        assert!(verifying_key.verify(message, &their_signature).is_ok());
+    });
+    report("验签", ours, theirs);
+
     println!("\n测试完成!");
 }
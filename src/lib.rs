@@ -1,7 +1,10 @@
+mod asn1;
 pub mod sm2;
 pub mod sm3;
 pub mod sm4;
+pub mod x509;
 
 pub use sm2::*;
 pub use sm3::*;
-pub use sm4::*;
\ No newline at end of file
+pub use sm4::*;
+pub use x509::*;
\ No newline at end of file
@@ -0,0 +1,218 @@
+// SM2批量验签
+//
+// 对每个签名有 [s]G + [t]P = R，其中 R = [k]G。取随机权重w_i，一次多标量乘检查
+//   [Σ w_i·s_i]G + Σ [w_i·t_i]P_i - Σ [w_i]R_i = O
+// SM2签名只含r，R的x坐标为 (r - e) mod n，y的奇偶未知，因此只有附带恢复标识的签名
+// 能参与合并检查；其余签名以及合并检查失败时逐个验签，找出无效签名
+
+use super::curve::{AffinePoint, ProjectivePoint, Scalar};
+use super::{public_key_point, sm2_message_digest, verify_with_point};
+use rand::RngCore;
+use rand::rngs::OsRng;
+
+/// 批量验签器，先收集 (公钥, 摘要e, 签名)，再一次性验证
+///
+/// 只有附带恢复标识的签名（`push_with_recovery_id`）能合并为一次多标量乘；
+/// 用 `push` 加入的签名仍会逐个验签，结果正确但没有批量加速
+#[derive(Clone, Default)]
+pub struct Sm2BatchVerifier {
+    items: Vec<BatchItem>,
+}
+
+#[derive(Clone)]
+struct BatchItem {
+    public_key: [u8; 64],
+    digest: [u8; 32],
+    signature: [u8; 64],
+    recovery_id: Option<u8>,
+}
+
+/// 解析通过、可参与合并检查的签名
+struct ParsedItem {
+    index: usize,
+    public_key: AffinePoint,
+    r_point: AffinePoint,
+    s: Scalar,
+    t: Scalar,
+}
+
+impl Sm2BatchVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 加入附带恢复标识（见 `sm2_sign_digest_recoverable`）的摘要e上的签名，可参与随机线性组合的合并检查
+    ///
+    /// e = SM3(Z_A || M) 可由 `sm2_message_digest` 计算。恢复标识错误不会导致误判，只会让该签名退回逐个验签
+    pub fn push_with_recovery_id(&mut self, public_key: &[u8; 64], digest: &[u8; 32], signature: &[u8; 64], recovery_id: u8) {
+        self.push_item(public_key, digest, signature, Some(recovery_id));
+    }
+
+    /// 加入消息M上的签名，按签名者ID计算 e = SM3(Z_A || M) 后同 `push_with_recovery_id`
+    ///
+    /// 公钥不在曲线上或ID超长时返回false，不加入该签名
+    pub fn push_message_with_recovery_id(
+        &mut self,
+        public_key: &[u8; 64],
+        id: &[u8],
+        message: &[u8],
+        signature: &[u8; 64],
+        recovery_id: u8,
+    ) -> bool {
+        let Some(digest) = sm2_message_digest(public_key, id, message) else {
+            return false;
+        };
+        self.push_item(public_key, &digest, signature, Some(recovery_id));
+        true
+    }
+
+    /// 加入不带恢复标识的摘要e上的签名
+    ///
+    /// 这样的签名无法恢复R，不参与合并检查，`verify` 时逐个验签
+    pub fn push(&mut self, public_key: &[u8; 64], digest: &[u8; 32], signature: &[u8; 64]) {
+        self.push_item(public_key, digest, signature, None);
+    }
+
+    fn push_item(&mut self, public_key: &[u8; 64], digest: &[u8; 32], signature: &[u8; 64], recovery_id: Option<u8>) {
+        self.items.push(BatchItem {
+            public_key: *public_key,
+            digest: *digest,
+            signature: *signature,
+            recovery_id,
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// 验证全部签名，全部有效返回Ok，否则返回无效签名的下标（按加入顺序升序）
+    pub fn verify(&self) -> Result<(), Vec<usize>> {
+        let mut invalid = Vec::new();
+        let mut parsed = Vec::new();
+        for (index, item) in self.items.iter().enumerate() {
+            match item.recovery_id.and_then(|id| parse_item(index, item, id)) {
+                Some(entry) => parsed.push(entry),
+                None => {
+                    if !item.verify_single() {
+                        invalid.push(index);
+                    }
+                }
+            }
+        }
+
+        // 合并检查失败时逐个确认，恢复标识错误的有效签名不会被误报
+        if !parsed.is_empty() && !check_combined(&parsed) {
+            for entry in &parsed {
+                if !self.items[entry.index].verify_single() {
+                    invalid.push(entry.index);
+                }
+            }
+        }
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            invalid.sort_unstable();
+            Err(invalid)
+        }
+    }
+}
+
+impl BatchItem {
+    fn verify_single(&self) -> bool {
+        match public_key_point(&self.public_key) {
+            Some(point) => verify_with_point(&point, &self.digest, &self.signature),
+            None => false,
+        }
+    }
+}
+
+/// 检查公钥与 r、s 范围并恢复R，任何一步失败都交给逐个验签处理
+fn parse_item(index: usize, item: &BatchItem, recovery_id: u8) -> Option<ParsedItem> {
+    let public_key = public_key_point(&item.public_key)?;
+    let r = Scalar::from_bytes(item.signature[..32].try_into().unwrap())?;
+    let s = Scalar::from_bytes(item.signature[32..].try_into().unwrap())?;
+    let t = r + s;
+    if bool::from(r.is_zero() | s.is_zero() | t.is_zero()) {
+        return None;
+    }
+
+    // x1 ≡ r - e (mod n)
+    let x = r - Scalar::from_bytes_reduced(&item.digest);
+    let r_point = AffinePoint::from_x_mod_n(&x, recovery_id)?;
+    Some(ParsedItem {
+        index,
+        public_key,
+        r_point,
+        s,
+        t,
+    })
+}
+
+/// 以128位随机权重检查 [Σ w_i·s_i]G + Σ [w_i·t_i]P_i - Σ [w_i]R_i = O
+///
+/// G项使用基点梳状预计算表，其余点共用一次交错wNAF多标量乘
+fn check_combined(items: &[ParsedItem]) -> bool {
+    let mut rng = OsRng;
+    let mut points = Vec::with_capacity(2 * items.len());
+    let mut scalars = Vec::with_capacity(2 * items.len());
+    let mut base_scalar = Scalar::ZERO;
+    for item in items {
+        let weight = random_weight(&mut rng);
+        base_scalar = base_scalar + weight * item.s;
+        points.push(ProjectivePoint::from(item.public_key));
+        scalars.push(weight * item.t);
+        points.push(ProjectivePoint::from(item.r_point).neg());
+        scalars.push(weight);
+    }
+    let sum = ProjectivePoint::multi_mul_vartime(&points, &scalars).add(&ProjectivePoint::mul_base(&base_scalar));
+    bool::from(sum.is_identity())
+}
+
+/// 非零的128位随机权重
+fn random_weight(rng: &mut OsRng) -> Scalar {
+    let mut bytes = [0u8; 32];
+    loop {
+        rng.fill_bytes(&mut bytes[16..]);
+        let weight = Scalar::from_bytes_reduced(&bytes);
+        if !bool::from(weight.is_zero()) {
+            return weight;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sm2::{SM2_DEFAULT_ID, sm2_generate_keypair, sm2_sign_digest_recoverable};
+
+    fn parsed_items(count: usize) -> Vec<ParsedItem> {
+        (0..count)
+            .map(|index| {
+                let (private_key, public_key) = sm2_generate_keypair();
+                let message = format!("batch item {}", index);
+                let digest = sm2_message_digest(&public_key, SM2_DEFAULT_ID, message.as_bytes()).unwrap();
+                let (signature, recovery_id) = sm2_sign_digest_recoverable(&private_key, &digest);
+                let item = BatchItem { public_key, digest, signature, recovery_id: Some(recovery_id) };
+                parse_item(index, &item, recovery_id).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_check_combined() {
+        let mut items = parsed_items(5);
+        assert!(check_combined(&items));
+        assert!(check_combined(&items[..1]));
+
+        // 篡改一个签名的s（t随之改变），合并检查必须失败
+        items[2].s = items[2].s + Scalar::ONE;
+        items[2].t = items[2].t + Scalar::ONE;
+        assert!(!check_combined(&items));
+    }
+}
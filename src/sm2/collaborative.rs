@@ -0,0 +1,432 @@
+// SM2两方协同签名与协同解密
+//
+// 私钥d不出现在任何一方，双方分别持有 d1、d2，满足 (1 + d)^-1 = d1·d2 mod n
+//
+// 密钥生成：
+//   客户端 → 服务端：P1 = [d1^-1]G
+//   服务端 → 客户端：P = [d2^-1]P1 - G（联合公钥）
+// 签名：
+//   客户端 → 服务端：Q1 = [k1]G，e
+//   服务端：(x1, y1) = [k3]Q1 + [k2]G，r = (e + x1) mod n，s2 = d2·k3，s3 = d2·(r + k2)
+//   服务端 → 客户端：r，s2，s3
+//   客户端：s = d1·k1·s2 + d1·s3 - r = (1 + d)^-1·(k - r·d)，其中 k = k1·k3 + k2
+// 解密：d = (d1·d2)^-1 - 1，故 [d]C1 = [d2^-1]([d1^-1]C1) - C1
+//   客户端 → 服务端：T1 = [d1^-1]C1
+//   服务端 → 客户端：T2 = [d2^-1]T1
+//   客户端：(x2, y2) = T2 - C1，之后与 `sm2_decrypt` 相同
+
+use super::curve::{AffinePoint, ProjectivePoint, Scalar};
+use super::{
+    SM2_DEFAULT_ID, message_digest, public_key_point, random_scalar, recover_plaintext, sm2_compute_z,
+    sm2_verify_digest,
+};
+use rand::rngs::OsRng;
+use zeroize::Zeroize;
+
+/// 密钥生成请求（客户端 → 服务端）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sm2CoSignKeygenRequest {
+    /// P1 = [d1^-1]G，x || y
+    pub p1: [u8; 64],
+}
+
+/// 密钥生成响应（服务端 → 客户端）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sm2CoSignKeygenResponse {
+    /// 联合公钥 P，x || y
+    pub public_key: [u8; 64],
+}
+
+/// 签名请求（客户端 → 服务端）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sm2CoSignRequest {
+    /// Q1 = [k1]G，x || y
+    pub q1: [u8; 64],
+    /// 待签名摘要 e = SM3(Z_A || M)
+    pub digest: [u8; 32],
+}
+
+/// 签名响应（服务端 → 客户端）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sm2CoSignResponse {
+    pub r: [u8; 32],
+    pub s2: [u8; 32],
+    pub s3: [u8; 32],
+}
+
+impl Sm2CoSignKeygenRequest {
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.p1
+    }
+
+    pub fn from_bytes(bytes: &[u8; 64]) -> Self {
+        Self { p1: *bytes }
+    }
+}
+
+impl Sm2CoSignKeygenResponse {
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.public_key
+    }
+
+    pub fn from_bytes(bytes: &[u8; 64]) -> Self {
+        Self { public_key: *bytes }
+    }
+}
+
+impl Sm2CoSignRequest {
+    /// 编码为 Q1 || e
+    pub fn to_bytes(&self) -> [u8; 96] {
+        let mut out = [0u8; 96];
+        out[..64].copy_from_slice(&self.q1);
+        out[64..].copy_from_slice(&self.digest);
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8; 96]) -> Self {
+        Self {
+            q1: bytes[..64].try_into().unwrap(),
+            digest: bytes[64..].try_into().unwrap(),
+        }
+    }
+}
+
+impl Sm2CoSignResponse {
+    /// 编码为 r || s2 || s3
+    pub fn to_bytes(&self) -> [u8; 96] {
+        let mut out = [0u8; 96];
+        out[..32].copy_from_slice(&self.r);
+        out[32..64].copy_from_slice(&self.s2);
+        out[64..].copy_from_slice(&self.s3);
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8; 96]) -> Self {
+        Self {
+            r: bytes[..32].try_into().unwrap(),
+            s2: bytes[32..64].try_into().unwrap(),
+            s3: bytes[64..].try_into().unwrap(),
+        }
+    }
+}
+
+/// 解密请求（客户端 → 服务端）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sm2CoDecryptRequest {
+    /// T1 = [d1^-1]C1，x || y
+    pub t1: [u8; 64],
+}
+
+/// 解密响应（服务端 → 客户端）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sm2CoDecryptResponse {
+    /// T2 = [d2^-1]T1，x || y
+    pub t2: [u8; 64],
+}
+
+impl Sm2CoDecryptRequest {
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.t1
+    }
+
+    pub fn from_bytes(bytes: &[u8; 64]) -> Self {
+        Self { t1: *bytes }
+    }
+}
+
+impl Sm2CoDecryptResponse {
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.t2
+    }
+
+    pub fn from_bytes(bytes: &[u8; 64]) -> Self {
+        Self { t2: *bytes }
+    }
+}
+
+/// 客户端密钥生成中间状态，持有d1，等待服务端返回联合公钥
+pub struct Sm2CoSignClientKeygen {
+    share: Scalar,
+}
+
+/// 客户端：持有私钥分量d1与联合公钥
+pub struct Sm2CoSignClient {
+    share: Scalar,
+    public_key: [u8; 64],
+}
+
+/// 客户端签名会话，持有k1，收到服务端响应后生成签名
+pub struct Sm2CoSignClientSession {
+    share: Scalar,
+    public_key: [u8; 64],
+    nonce: Scalar,
+    digest: [u8; 32],
+}
+
+/// 客户端解密会话，保存密文，收到服务端响应后还原明文
+pub struct Sm2CoDecryptSession {
+    c1: AffinePoint,
+    ciphertext: Vec<u8>,
+}
+
+/// 服务端：持有私钥分量d2与联合公钥
+pub struct Sm2CoSignServer {
+    share: Scalar,
+    public_key: [u8; 64],
+}
+
+impl Sm2CoSignClientKeygen {
+    /// 生成d1并给出发送给服务端的请求
+    pub fn new() -> (Self, Sm2CoSignKeygenRequest) {
+        let share = random_scalar(&mut OsRng);
+        let mut inverse = share.invert().unwrap();
+        let p1 = ProjectivePoint::mul_base(&inverse).to_affine().unwrap();
+        inverse.zeroize();
+        (Self { share }, Sm2CoSignKeygenRequest { p1: p1.to_bytes() })
+    }
+
+    /// 收到联合公钥后完成密钥生成，公钥无效时返回None
+    pub fn finish(self, response: &Sm2CoSignKeygenResponse) -> Option<Sm2CoSignClient> {
+        public_key_point(&response.public_key)?;
+        Some(Sm2CoSignClient {
+            share: self.share,
+            public_key: response.public_key,
+        })
+    }
+}
+
+impl Sm2CoSignClient {
+    /// 由保存的d1与联合公钥恢复客户端，d1为零或不小于n、公钥无效时返回None
+    pub fn from_parts(share: &[u8; 32], public_key: &[u8; 64]) -> Option<Self> {
+        public_key_point(public_key)?;
+        Some(Self {
+            share: nonzero_scalar(share)?,
+            public_key: *public_key,
+        })
+    }
+
+    /// 私钥分量d1，用于持久化
+    pub fn share(&self) -> [u8; 32] {
+        self.share.to_bytes()
+    }
+
+    /// 联合公钥，与 `sm2_verify` 使用的公钥格式相同
+    pub fn public_key(&self) -> [u8; 64] {
+        self.public_key
+    }
+
+    /// 使用默认用户身份标识开始对消息签名
+    pub fn sign_start(&self, message: &[u8]) -> (Sm2CoSignClientSession, Sm2CoSignRequest) {
+        self.sign_start_with_id(SM2_DEFAULT_ID, message).unwrap()
+    }
+
+    /// 使用指定用户身份标识开始对消息签名，ID超长时返回None
+    pub fn sign_start_with_id(&self, id: &[u8], message: &[u8]) -> Option<(Sm2CoSignClientSession, Sm2CoSignRequest)> {
+        let z = sm2_compute_z(&self.public_key, id)?;
+        Some(self.sign_digest_start(&message_digest(&z, message)))
+    }
+
+    /// 开始对预先计算好的摘要e签名
+    pub fn sign_digest_start(&self, digest: &[u8; 32]) -> (Sm2CoSignClientSession, Sm2CoSignRequest) {
+        let nonce = random_scalar(&mut OsRng);
+        let q1 = ProjectivePoint::mul_base(&nonce).to_affine().unwrap();
+        let session = Sm2CoSignClientSession {
+            share: self.share,
+            public_key: self.public_key,
+            nonce,
+            digest: *digest,
+        };
+        (session, Sm2CoSignRequest { q1: q1.to_bytes(), digest: *digest })
+    }
+
+    /// 开始解密发给联合公钥的密文（C1 || C3 || C2，与 `sm2_decrypt` 的输入相同）
+    ///
+    /// 密文过短、前缀不是0x04或C1不在曲线上时返回None
+    pub fn decrypt_start(&self, ciphertext: &[u8]) -> Option<(Sm2CoDecryptSession, Sm2CoDecryptRequest)> {
+        if ciphertext.len() < 1 + 64 + 32 + 1 || ciphertext[0] != 0x04 {
+            return None;
+        }
+        let c1 = AffinePoint::from_bytes(ciphertext[1..65].try_into().unwrap())?;
+        let mut inverse = self.share.invert()?;
+        let t1 = ProjectivePoint::from(c1).mul(&inverse).to_affine();
+        inverse.zeroize();
+        let t1 = t1?;
+        let session = Sm2CoDecryptSession {
+            c1,
+            ciphertext: ciphertext.to_vec(),
+        };
+        Some((session, Sm2CoDecryptRequest { t1: t1.to_bytes() }))
+    }
+}
+
+impl Sm2CoDecryptSession {
+    /// 由服务端响应还原明文，T2无效或C3校验失败时返回None
+    pub fn finish(self, response: &Sm2CoDecryptResponse) -> Option<Vec<u8>> {
+        let t2 = AffinePoint::from_bytes(&response.t2)?;
+
+        // (x2, y2) = T2 - C1
+        let mut shared = ProjectivePoint::from(t2)
+            .add(&ProjectivePoint::from(self.c1).neg())
+            .to_affine()?
+            .to_bytes();
+        let plaintext = recover_plaintext(&shared, &self.ciphertext[65..97], &self.ciphertext[97..]);
+        shared.zeroize();
+        plaintext
+    }
+}
+
+/// 会话保存的密文副本在释放时清零
+impl Drop for Sm2CoDecryptSession {
+    fn drop(&mut self) {
+        self.ciphertext.zeroize();
+    }
+}
+
+impl Sm2CoSignClientSession {
+    /// 由服务端响应计算签名 r || s
+    ///
+    /// 响应不在范围内、s = 0、r + s = n 或结果不能通过验签时返回None，此时重新发起签名
+    pub fn finish(mut self, response: &Sm2CoSignResponse) -> Option<[u8; 64]> {
+        let r = nonzero_scalar(&response.r)?;
+        let s2 = nonzero_scalar(&response.s2)?;
+        let s3 = nonzero_scalar(&response.s3)?;
+
+        // s = d1·k1·s2 + d1·s3 - r，k1只用于本次签名
+        let s = self.share * self.nonce * s2 + self.share * s3 - r;
+        self.nonce.zeroize();
+        if bool::from(s.is_zero() | (r + s).is_zero()) {
+            return None;
+        }
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&r.to_bytes());
+        signature[32..].copy_from_slice(&s.to_bytes());
+
+        // 服务端行为异常时不输出无效签名
+        if !sm2_verify_digest(&self.public_key, &self.digest, &signature) {
+            return None;
+        }
+        Some(signature)
+    }
+}
+
+/// 私钥分量与签名随机数在释放时清零
+impl Drop for Sm2CoSignClientKeygen {
+    fn drop(&mut self) {
+        self.share.zeroize();
+    }
+}
+
+impl Drop for Sm2CoSignClient {
+    fn drop(&mut self) {
+        self.share.zeroize();
+    }
+}
+
+impl Drop for Sm2CoSignClientSession {
+    fn drop(&mut self) {
+        self.share.zeroize();
+        self.nonce.zeroize();
+    }
+}
+
+impl Drop for Sm2CoSignServer {
+    fn drop(&mut self) {
+        self.share.zeroize();
+    }
+}
+
+impl Sm2CoSignServer {
+    /// 收到客户端请求后生成d2与联合公钥 P = [d2^-1]P1 - G
+    ///
+    /// P1无效时返回None
+    pub fn keygen(request: &Sm2CoSignKeygenRequest) -> Option<(Self, Sm2CoSignKeygenResponse)> {
+        let p1 = ProjectivePoint::from(public_key_point(&request.p1)?);
+        let mut rng = OsRng;
+        loop {
+            let mut share = random_scalar(&mut rng);
+            let mut inverse = share.invert()?;
+            let joint = p1.mul(&inverse).add(&ProjectivePoint::generator().neg());
+            inverse.zeroize();
+            // P为无穷远点时 d = 0，重新选取d2
+            let Some(public_key) = joint.to_affine() else {
+                share.zeroize();
+                continue;
+            };
+            let public_key = public_key.to_bytes();
+            return Some((Self { share, public_key }, Sm2CoSignKeygenResponse { public_key }));
+        }
+    }
+
+    /// 由保存的d2与联合公钥恢复服务端，d2为零或不小于n、公钥无效时返回None
+    pub fn from_parts(share: &[u8; 32], public_key: &[u8; 64]) -> Option<Self> {
+        public_key_point(public_key)?;
+        Some(Self {
+            share: nonzero_scalar(share)?,
+            public_key: *public_key,
+        })
+    }
+
+    /// 私钥分量d2，用于持久化
+    pub fn share(&self) -> [u8; 32] {
+        self.share.to_bytes()
+    }
+
+    /// 联合公钥
+    pub fn public_key(&self) -> [u8; 64] {
+        self.public_key
+    }
+
+    /// 处理签名请求，Q1无效时返回None
+    pub fn sign(&self, request: &Sm2CoSignRequest) -> Option<Sm2CoSignResponse> {
+        let q1 = ProjectivePoint::from(public_key_point(&request.q1)?);
+        let e = Scalar::from_bytes_reduced(&request.digest);
+        let mut rng = OsRng;
+        loop {
+            let mut k2 = random_scalar(&mut rng);
+            let mut k3 = random_scalar(&mut rng);
+            let response = self.sign_with_nonces(&q1, &e, &k2, &k3);
+            k2.zeroize();
+            k3.zeroize();
+            if response.is_some() {
+                return response;
+            }
+        }
+    }
+
+    /// 用随机数k2、k3计算一次签名响应，r = 0 或 s3 = 0 时返回None，由调用方重新选取
+    fn sign_with_nonces(&self, q1: &ProjectivePoint, e: &Scalar, k2: &Scalar, k3: &Scalar) -> Option<Sm2CoSignResponse> {
+        // (x1, y1) = [k3]Q1 + [k2]G
+        let point = q1.mul(k3).add(&ProjectivePoint::mul_base(k2)).to_affine()?;
+        let r = *e + Scalar::from_bytes_reduced(&point.x.to_bytes());
+        if bool::from(r.is_zero()) {
+            return None;
+        }
+
+        let s2 = self.share * *k3;
+        let s3 = self.share * (r + *k2);
+        if bool::from(s3.is_zero()) {
+            return None;
+        }
+        Some(Sm2CoSignResponse {
+            r: r.to_bytes(),
+            s2: s2.to_bytes(),
+            s3: s3.to_bytes(),
+        })
+    }
+
+    /// 处理解密请求，T1不在曲线上时返回None
+    pub fn decrypt(&self, request: &Sm2CoDecryptRequest) -> Option<Sm2CoDecryptResponse> {
+        let t1 = ProjectivePoint::from(public_key_point(&request.t1)?);
+        let mut inverse = self.share.invert()?;
+        let t2 = t1.mul(&inverse).to_affine();
+        inverse.zeroize();
+        Some(Sm2CoDecryptResponse { t2: t2?.to_bytes() })
+    }
+}
+
+/// 解析 [1, n-1] 中的标量
+fn nonzero_scalar(bytes: &[u8; 32]) -> Option<Scalar> {
+    let k = Scalar::from_bytes(bytes)?;
+    if bool::from(k.is_zero()) { None } else { Some(k) }
+}
@@ -0,0 +1,816 @@
+// SM2推荐曲线 sm2p256v1 的有限域、标量与点运算
+//
+// 曲线方程：y² = x³ + ax + b (mod p)，a = p - 3，余因子 h = 1
+// 参数取自 GB/T 32918.5-2017
+
+use crypto_bigint::modular::ConstMontyForm;
+use crypto_bigint::{Limb, U256, impl_modulus};
+use std::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use std::sync::OnceLock;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+pub use super::field::FieldElement;
+
+/// 素数p
+pub const SM2_P: [u8; 32] = hex32("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF00000000FFFFFFFFFFFFFFFF");
+/// 曲线参数a
+pub const SM2_A: [u8; 32] = hex32("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF00000000FFFFFFFFFFFFFFFC");
+/// 曲线参数b
+pub const SM2_B: [u8; 32] = hex32("28E9FA9E9D9F5E344D5A9E4BCF6509A7F39789F515AB8F92DDBCBD414D940E93");
+/// 基点G的x坐标
+pub const SM2_GX: [u8; 32] = hex32("32C4AE2C1F1981195F9904466A39C9948FE30BBFF2660BE1715A4589334C74C7");
+/// 基点G的y坐标
+pub const SM2_GY: [u8; 32] = hex32("BC3736A2F4F6779C59BDCEE36B692153D0A9877CC62A474002DF32E52139F0A0");
+/// 基点G的阶n
+pub const SM2_N: [u8; 32] = hex32("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFF7203DF6B21C6052B53BBF40939D54123");
+
+impl_modulus!(
+    OrderModulus,
+    U256,
+    "FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFF7203DF6B21C6052B53BBF40939D54123"
+);
+
+type FnMonty = ConstMontyForm<OrderModulus, { U256::LIMBS }>;
+
+const P: U256 = U256::from_be_slice(&SM2_P);
+const N: U256 = U256::from_be_slice(&SM2_N);
+
+/// 编译期把64位十六进制串转换为32字节大端数组
+const fn hex32(s: &str) -> [u8; 32] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+    let bytes = s.as_bytes();
+    assert!(bytes.len() == 64);
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        out[i] = (nibble(bytes[2 * i]) << 4) | nibble(bytes[2 * i + 1]);
+        i += 1;
+    }
+    out
+}
+
+/// 固定时间比较 a < b
+fn ct_lt(a: &U256, b: &U256) -> Choice {
+    let (_, borrow) = a.sbb(b, Limb::ZERO);
+    Choice::from((borrow.0 & 1) as u8)
+}
+
+/// 把32字节大端整数约减到 [0, m-1]，要求输入小于 2m
+fn reduce_once(bytes: &[u8; 32], m: &U256) -> U256 {
+    let x = U256::from_be_slice(bytes);
+    let (d, borrow) = x.sbb(m, Limb::ZERO);
+    U256::conditional_select(&d, &x, Choice::from((borrow.0 & 1) as u8))
+}
+
+/// 模n的标量
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scalar(FnMonty);
+
+impl Scalar {
+    pub const ZERO: Self = Self(FnMonty::ZERO);
+    pub const ONE: Self = Self(FnMonty::ONE);
+
+    /// 从32字节大端编码解析，要求取值小于n
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let x = U256::from_be_slice(bytes);
+        if bool::from(ct_lt(&x, &N)) {
+            Some(Self(FnMonty::new(&x)))
+        } else {
+            None
+        }
+    }
+
+    /// 从32字节大端编码解析并约减模n
+    pub fn from_bytes_reduced(bytes: &[u8; 32]) -> Self {
+        // n > 2^255，任意256位整数至多减一次n
+        Self(FnMonty::new(&reduce_once(bytes, &N)))
+    }
+
+    /// 输出32字节大端编码
+    pub fn to_bytes(self) -> [u8; 32] {
+        self.0.retrieve().to_be_bytes()
+    }
+
+    /// 乘法逆元，零没有逆元
+    pub fn invert(&self) -> Option<Self> {
+        Option::<FnMonty>::from(self.0.inv()).map(Self)
+    }
+
+    pub fn is_zero(&self) -> Choice {
+        self.0.ct_eq(&FnMonty::ZERO)
+    }
+
+    /// 标量盲化后固定时间重编码：k' = k + r·n（r < 2^63，故 k' < 2^320），[k']P = [k]P
+    ///
+    /// 输出81个有符号16进制数字 k' = Σ d_i·16^i，d_i ∈ [-8, 7]，最高位 d_80 ∈ {0, 1}
+    pub(crate) fn blinded_radix16(&self, blind: u64) -> [i8; 81] {
+        let blind = blind >> 1;
+        let bytes = self.to_bytes();
+        let order = N.to_be_bytes();
+
+        // k + r·n，按64位小端limb计算
+        let mut limbs = [0u64; 5];
+        let mut carry = 0u128;
+        for (i, limb) in limbs.iter_mut().take(4).enumerate() {
+            let k = u64::from_be_bytes(bytes[24 - 8 * i..32 - 8 * i].try_into().unwrap());
+            let n = u64::from_be_bytes(order[24 - 8 * i..32 - 8 * i].try_into().unwrap());
+            let t = k as u128 + n as u128 * blind as u128 + carry;
+            *limb = t as u64;
+            carry = t >> 64;
+        }
+        limbs[4] = carry as u64;
+
+        let mut digits = [0i8; 81];
+        let mut carry = 0i8;
+        for (i, digit) in digits.iter_mut().take(80).enumerate() {
+            let nibble = ((limbs[i / 16] >> (4 * (i % 16))) & 0x0F) as i8;
+            let d = nibble + carry;
+            carry = (d + 8) >> 4;
+            *digit = d - (carry << 4);
+        }
+        digits[80] = carry;
+        digits
+    }
+
+    /// 宽度为w的NAF：非零数字均为奇数且绝对值小于 2^(w-1)，任意w个相邻数字中至多一个非零
+    ///
+    /// 运行时间依赖标量，只能用于公开数据
+    pub(crate) fn wnaf(&self, w: usize) -> [i8; 264] {
+        debug_assert!((2..=7).contains(&w));
+        let bytes = self.to_bytes();
+        let mut limbs = [0u64; 5];
+        for (i, limb) in limbs.iter_mut().take(4).enumerate() {
+            *limb = u64::from_be_bytes(bytes[24 - 8 * i..32 - 8 * i].try_into().unwrap());
+        }
+
+        let width = 1u64 << w;
+        let mask = width - 1;
+        let mut naf = [0i8; 264];
+        let mut pos = 0;
+        let mut carry = 0u64;
+        while pos < 256 {
+            let (index, shift) = (pos / 64, pos % 64);
+            let bits = if shift + w <= 64 {
+                limbs[index] >> shift
+            } else {
+                (limbs[index] >> shift) | (limbs[index + 1] << (64 - shift))
+            };
+            let window = carry + (bits & mask);
+            if window & 1 == 0 {
+                pos += 1;
+                continue;
+            }
+            if window < width / 2 {
+                carry = 0;
+                naf[pos] = window as i8;
+            } else {
+                carry = 1;
+                naf[pos] = (window as i64 - width as i64) as i8;
+            }
+            pos += w;
+        }
+        // 最高的进位落在 pos（不超过 256 + w - 1）
+        naf[pos] = carry as i8;
+        naf
+    }
+}
+
+impl Add for Scalar {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.add(&rhs.0))
+    }
+}
+
+impl Sub for Scalar {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.sub(&rhs.0))
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0.mul(&rhs.0))
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(self.0.neg())
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(FnMonty::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+/// 私钥、随机数等秘密标量在释放前清零
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// 仿射坐标点，不表示无穷远点
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AffinePoint {
+    pub x: FieldElement,
+    pub y: FieldElement,
+}
+
+impl AffinePoint {
+    /// 基点G
+    pub fn generator() -> Self {
+        Self {
+            x: FieldElement::from_bytes(&SM2_GX).unwrap(),
+            y: FieldElement::from_bytes(&SM2_GY).unwrap(),
+        }
+    }
+
+    /// 从 x || y 编码解析，坐标须小于p且点须在曲线上
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<Self> {
+        let x = FieldElement::from_bytes(bytes[..32].try_into().unwrap())?;
+        let y = FieldElement::from_bytes(bytes[32..].try_into().unwrap())?;
+        let point = Self { x, y };
+        if point.is_on_curve() {
+            Some(point)
+        } else {
+            None
+        }
+    }
+
+    /// 输出 x || y 编码
+    pub fn to_bytes(self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&self.x.to_bytes());
+        out[32..].copy_from_slice(&self.y.to_bytes());
+        out
+    }
+
+    /// 由x坐标与y的奇偶恢复点，x不对应曲线上的点时返回None
+    pub fn decompress(x: &[u8; 32], y_is_odd: Choice) -> Option<Self> {
+        let x = FieldElement::from_bytes(x)?;
+        let a = FieldElement::from_bytes(&SM2_A).unwrap();
+        let b = FieldElement::from_bytes(&SM2_B).unwrap();
+        let y = (x.square() * x + a * x + b).sqrt()?;
+        let y = FieldElement::conditional_select(&y, &-y, y.is_odd() ^ y_is_odd);
+        Some(Self { x, y })
+    }
+
+    /// 由 x mod n 与恢复标识恢复点：第0位为y的奇偶，第1位表示 x = (x mod n) + n
+    ///
+    /// 签名中R = [k]G 的x坐标只以 x mod n 的形式出现，恢复标识消除其歧义
+    pub fn from_x_mod_n(x: &Scalar, recovery_id: u8) -> Option<Self> {
+        if recovery_id > 3 {
+            return None;
+        }
+        let mut x = U256::from_be_slice(&x.to_bytes());
+        if recovery_id & 2 != 0 {
+            // 要求 x + n < p，先比较再相加以免溢出
+            if !bool::from(ct_lt(&x, &P.wrapping_sub(&N))) {
+                return None;
+            }
+            x = x.wrapping_add(&N);
+        }
+        Self::decompress(&x.to_be_bytes(), Choice::from(recovery_id & 1))
+    }
+
+    pub fn neg(&self) -> Self {
+        Self { x: self.x, y: -self.y }
+    }
+
+    /// 检查 [n]P = O
+    ///
+    /// SM2曲线余因子为1，曲线上的点都满足；导入公钥时仍显式检查，防止小子群点
+    pub fn has_order_n(&self) -> bool {
+        let point = ProjectivePoint::from(*self);
+        bool::from(point.mul_vartime(&-Scalar::ONE).add(&point).is_identity())
+    }
+
+    /// 检查 y² = x³ + ax + b
+    pub fn is_on_curve(&self) -> bool {
+        let a = FieldElement::from_bytes(&SM2_A).unwrap();
+        let b = FieldElement::from_bytes(&SM2_B).unwrap();
+        let rhs = self.x.square() * self.x + a * self.x + b;
+        bool::from(self.y.square().ct_eq(&rhs))
+    }
+}
+
+/// Jacobian射影坐标点 (X, Y, Z)，对应仿射坐标 (X/Z², Y/Z³)，Z = 0 表示无穷远点
+#[derive(Clone, Copy, Debug)]
+pub struct ProjectivePoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+}
+
+impl ProjectivePoint {
+    /// 无穷远点O
+    pub const IDENTITY: Self = Self {
+        x: FieldElement::ONE,
+        y: FieldElement::ONE,
+        z: FieldElement::ZERO,
+    };
+
+    /// 基点G
+    pub fn generator() -> Self {
+        AffinePoint::generator().into()
+    }
+
+    pub fn is_identity(&self) -> Choice {
+        self.z.is_zero()
+    }
+
+    /// 转换为仿射坐标，无穷远点返回None
+    pub fn to_affine(self) -> Option<AffinePoint> {
+        let z_inv = self.z.invert()?;
+        let z_inv2 = z_inv.square();
+        Some(AffinePoint {
+            x: self.x * z_inv2,
+            y: self.y * z_inv2 * z_inv,
+        })
+    }
+
+    /// 倍点，使用 a = -3 的 dbl-2001-b 公式
+    pub fn double(&self) -> Self {
+        let delta = self.z.square();
+        let gamma = self.y.square();
+        let beta = self.x * gamma;
+        let t = (self.x - delta) * (self.x + delta);
+        let alpha = t.double() + t;
+        let beta4 = beta.double().double();
+        let x3 = alpha.square() - beta4.double();
+        let z3 = (self.y + self.z).square() - gamma - delta;
+        let gamma2 = gamma.square();
+        let y3 = alpha * (beta4 - x3) - gamma2.double().double().double();
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    /// 点加，使用 add-2007-bl 公式，并以条件选择处理无穷远点与相同点
+    pub fn add(&self, other: &Self) -> Self {
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * other.z * z2z2;
+        let s2 = other.y * self.z * z1z1;
+        let h = u2 - u1;
+        let i = h.double().square();
+        let j = h * i;
+        let r = (s2 - s1).double();
+        let v = u1 * i;
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (s1 * j).double();
+        let z3 = ((self.z + other.z).square() - z1z1 - z2z2) * h;
+        let mut sum = Self { x: x3, y: y3, z: z3 };
+
+        // 两点相同（H = 0 且 r = 0）时公式退化，改用倍点
+        let same = h.is_zero() & r.is_zero();
+        sum.conditional_assign(&self.double(), same);
+        sum.conditional_assign(other, self.is_identity());
+        sum.conditional_assign(self, other.is_identity());
+        sum
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+
+    /// 标量乘 [k]P，用于秘密标量
+    ///
+    /// 有符号4位固定窗口：预计算 [1]P..[8]P 后每个窗口做4次倍点和一次点加，
+    /// 查表读取全部表项再条件选择，不存在依赖秘密的分支与访存地址；
+    /// 标量每次随机盲化，P的射影坐标每次随机化
+    pub fn mul(&self, k: &Scalar) -> Self {
+        let mut rng = OsRng;
+        let digits = k.blinded_radix16(rng.next_u64());
+        let base = self.randomize(&mut rng);
+        let mut table = [base; 8];
+        for j in 1..8 {
+            table[j] = table[j - 1].add(&base);
+        }
+
+        let mut acc = Self::IDENTITY;
+        for &digit in digits.iter().rev() {
+            for _ in 0..4 {
+                acc = acc.double();
+            }
+            let (point, is_zero) = lookup(&table, digit);
+            let sum = acc.add(&point);
+            acc.conditional_assign(&sum, !is_zero);
+        }
+        acc
+    }
+
+    /// 射影坐标随机化 (X, Y, Z) → (λ²X, λ³Y, λZ)，λ为随机非零域元素
+    fn randomize(&self, rng: &mut OsRng) -> Self {
+        let mut bytes = [0u8; 32];
+        let lambda = loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(lambda) = FieldElement::from_bytes(&bytes)
+                && !bool::from(lambda.is_zero())
+            {
+                break lambda;
+            }
+        };
+        let lambda2 = lambda.square();
+        Self {
+            x: self.x * lambda2,
+            y: self.y * lambda2 * lambda,
+            z: self.z * lambda,
+        }
+    }
+
+    /// 混合加法 self + Q（Q为仿射点），使用 madd-2007-bl 公式，特殊情况处理同 `add`
+    pub fn add_mixed(&self, other: &AffinePoint) -> Self {
+        let z1z1 = self.z.square();
+        let u2 = other.x * z1z1;
+        let s2 = other.y * self.z * z1z1;
+        let h = u2 - self.x;
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = h * i;
+        let r = (s2 - self.y).double();
+        let v = self.x * i;
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (self.y * j).double();
+        let z3 = (self.z + h).square() - z1z1 - hh;
+        let mut sum = Self { x: x3, y: y3, z: z3 };
+
+        let same = h.is_zero() & r.is_zero() & !self.is_identity();
+        sum.conditional_assign(&self.double(), same);
+        sum.conditional_assign(&Self::from(*other), self.is_identity());
+        sum
+    }
+
+    /// 基点标量乘 [k]G，用于秘密标量
+    ///
+    /// 梳状预计算表保存 [j·16^i]G（j = 1..=8），盲化后的标量重编码为有符号16进制数字，
+    /// 每个窗口固定时间读取整行并做一次混合加法，不需要倍点。
+    /// 累加器从随机化射影坐标的G开始，最后再减去G
+    pub fn mul_base(k: &Scalar) -> Self {
+        let mut rng = OsRng;
+        let digits = k.blinded_radix16(rng.next_u64());
+        let mut acc = Self::generator().randomize(&mut rng);
+        for (row, &digit) in base_table().iter().zip(digits.iter()) {
+            let (point, is_zero) = lookup(row, digit);
+            let sum = acc.add_mixed(&point);
+            acc.conditional_assign(&sum, !is_zero);
+        }
+        acc.add_mixed(&AffinePoint::generator().neg())
+    }
+
+    /// 标量乘 [k]P，wNAF，运行时间依赖标量，只能用于公开数据
+    pub fn mul_vartime(&self, k: &Scalar) -> Self {
+        Self::multi_mul_vartime(&[*self], &[*k])
+    }
+
+    /// [a]G + [b]P，交错wNAF（Shamir技巧）：G使用宽度7的预计算奇数倍表，P使用宽度5
+    ///
+    /// 运行时间依赖标量，只能用于验签等公开数据
+    pub fn mul_double_base_vartime(a: &Scalar, b: &Scalar, point: &Self) -> Self {
+        let base = base_odd_multiples();
+        let multiples = point.odd_multiples::<8>();
+        let naf_a = a.wnaf(7);
+        let naf_b = b.wnaf(5);
+
+        // 跳过两个标量共同的前导零
+        let top = (0..naf_a.len()).rev().find(|&i| naf_a[i] != 0 || naf_b[i] != 0).map_or(0, |i| i + 1);
+        let mut acc = Self::IDENTITY;
+        for i in (0..top).rev() {
+            acc = acc.double();
+            let da = naf_a[i];
+            if da > 0 {
+                acc = acc.add_mixed(&base[(da / 2) as usize]);
+            } else if da < 0 {
+                acc = acc.add_mixed(&base[(-da / 2) as usize].neg());
+            }
+            let db = naf_b[i];
+            if db > 0 {
+                acc = acc.add(&multiples[(db / 2) as usize]);
+            } else if db < 0 {
+                acc = acc.add(&multiples[(-db / 2) as usize].neg());
+            }
+        }
+        acc
+    }
+
+    /// 多标量乘 Σ[k_i]P_i（交错wNAF，宽度5，所有点共用倍点）
+    ///
+    /// 运行时间依赖标量，只能用于公开数据（如验签）
+    pub fn multi_mul_vartime(points: &[Self], scalars: &[Scalar]) -> Self {
+        assert_eq!(points.len(), scalars.len());
+        let tables: Vec<[Self; 8]> = points.iter().map(Self::odd_multiples::<8>).collect();
+        let nafs: Vec<[i8; 264]> = scalars.iter().map(|k| k.wnaf(5)).collect();
+
+        let top = (0..264).rev().find(|&i| nafs.iter().any(|naf| naf[i] != 0)).map_or(0, |i| i + 1);
+        let mut acc = Self::IDENTITY;
+        for i in (0..top).rev() {
+            acc = acc.double();
+            for (table, naf) in tables.iter().zip(&nafs) {
+                let d = naf[i];
+                if d > 0 {
+                    acc = acc.add(&table[(d / 2) as usize]);
+                } else if d < 0 {
+                    acc = acc.add(&table[(-d / 2) as usize].neg());
+                }
+            }
+        }
+        acc
+    }
+
+    /// 奇数倍表 [1]P, [3]P, …, [2N-1]P
+    fn odd_multiples<const N: usize>(&self) -> [Self; N] {
+        let double = self.double();
+        let mut table = [*self; N];
+        for i in 1..N {
+            table[i] = table[i - 1].add(&double);
+        }
+        table
+    }
+}
+
+/// 梳状表的窗口数：盲化标量不超过320位，有符号16进制重编码后最高位的进位需要第81个窗口
+const BASE_WINDOWS: usize = 81;
+
+/// 基点梳状表，第i行保存 [j·16^i]G，j = 1..=8，首次使用时构建
+fn base_table() -> &'static [[AffinePoint; 8]] {
+    static TABLE: OnceLock<Vec<[AffinePoint; 8]>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = Vec::with_capacity(BASE_WINDOWS);
+        let mut base = ProjectivePoint::generator();
+        for _ in 0..BASE_WINDOWS {
+            let mut row = [AffinePoint::generator(); 8];
+            let mut multiple = base;
+            for entry in row.iter_mut() {
+                *entry = multiple.to_affine().unwrap();
+                multiple = multiple.add(&base);
+            }
+            table.push(row);
+            for _ in 0..4 {
+                base = base.double();
+            }
+        }
+        table
+    })
+}
+
+/// 基点奇数倍表 [1]G, [3]G, …, [63]G，首次使用时构建
+fn base_odd_multiples() -> &'static [AffinePoint; 32] {
+    static TABLE: OnceLock<[AffinePoint; 32]> = OnceLock::new();
+    TABLE.get_or_init(|| ProjectivePoint::generator().odd_multiples::<32>().map(|p| p.to_affine().unwrap()))
+}
+
+/// 固定时间取 [|d|]·row[0] 并按d的符号取负；d = 0 时返回的点无意义，由第二个返回值标明
+///
+/// 依次读取整行表项并条件选择，访存地址与d无关
+fn lookup<T: LookupEntry>(row: &[T; 8], digit: i8) -> (T, Choice) {
+    let sign = digit >> 7;
+    let abs = ((digit ^ sign) - sign) as u8;
+    let mut point = row[0];
+    for (j, entry) in row.iter().enumerate() {
+        point.conditional_assign(entry, (j as u8 + 1).ct_eq(&abs));
+    }
+    let negated = point.negate();
+    point.conditional_assign(&negated, Choice::from((sign & 1) as u8));
+    (point, abs.ct_eq(&0))
+}
+
+/// 可固定时间查表的点表示
+trait LookupEntry: ConditionallySelectable {
+    fn negate(&self) -> Self;
+}
+
+impl LookupEntry for AffinePoint {
+    fn negate(&self) -> Self {
+        self.neg()
+    }
+}
+
+impl LookupEntry for ProjectivePoint {
+    fn negate(&self) -> Self {
+        self.neg()
+    }
+}
+
+impl From<AffinePoint> for ProjectivePoint {
+    fn from(p: AffinePoint) -> Self {
+        Self {
+            x: p.x,
+            y: p.y,
+            z: FieldElement::ONE,
+        }
+    }
+}
+
+impl ConditionallySelectable for AffinePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+        }
+    }
+}
+
+impl ConditionallySelectable for ProjectivePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            z: FieldElement::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl PartialEq for ProjectivePoint {
+    fn eq(&self, other: &Self) -> bool {
+        // X1·Z2² = X2·Z1² 且 Y1·Z2³ = Y2·Z1³
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let x_eq = (self.x * z2z2).ct_eq(&(other.x * z1z1));
+        let y_eq = (self.y * z2z2 * other.z).ct_eq(&(other.y * z1z1 * self.z));
+        let both_identity = self.is_identity() & other.is_identity();
+        let neither_identity = !self.is_identity() & !other.is_identity();
+        bool::from(both_identity | (neither_identity & x_eq & y_eq))
+    }
+}
+
+impl Eq for ProjectivePoint {}
+
+#[cfg(test)]
+mod tests {
+    // SM2椭圆曲线点运算测试
+
+    use super::*;
+
+    fn scalar_from_hex(hex: &str) -> Scalar {
+        let mut bytes = [0u8; 32];
+        for i in 0..32 {
+            bytes[i] = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        Scalar::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_point_add() {
+        // 测试点加功能
+        let g = ProjectivePoint::generator();
+        let three = Scalar::ONE + Scalar::ONE + Scalar::ONE;
+        assert_eq!(g.add(&g).add(&g), ProjectivePoint::mul_base(&three));
+        assert_eq!(g.add(&g.neg()).is_identity().unwrap_u8(), 1);
+        assert_eq!(g.add(&ProjectivePoint::IDENTITY), g);
+        assert_eq!(ProjectivePoint::IDENTITY.add(&g), g);
+    }
+
+    #[test]
+    fn test_point_double() {
+        // 测试点倍功能
+        let g = ProjectivePoint::generator();
+        assert_eq!(g.double(), g.add(&g));
+        let two_g = g.double().to_affine().unwrap();
+        assert!(two_g.is_on_curve());
+        assert_eq!(ProjectivePoint::IDENTITY.double().is_identity().unwrap_u8(), 1);
+    }
+
+    #[test]
+    fn test_point_mul() {
+        // 测试点乘功能
+        let g = ProjectivePoint::generator();
+        let k = scalar_from_hex("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B");
+        let m = scalar_from_hex("0000000000000000000000000000000000000000000000000000000000010001");
+        let p = g.mul(&k);
+        assert_eq!(p.add(&g.mul(&m)), g.mul(&(k + m)));
+        assert_eq!(p.mul(&m), g.mul(&(k * m)));
+
+        // [n]G = O
+        let n_minus_1 = -Scalar::ONE;
+        assert_eq!(g.mul(&n_minus_1).add(&g).is_identity().unwrap_u8(), 1);
+        assert!(Scalar::from_bytes(&SM2_N).is_none());
+    }
+
+    #[test]
+    fn test_point_mul_g() {
+        // 测试点乘生成元G（GB/T 32918.5 示例密钥对）
+        let d = scalar_from_hex("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8");
+        let expected_x = "09F9DF311E5421A150DD7D161E4BC5C672179FAD1833FC076BB08FF356F35020";
+        let expected_y = "CCEA490CE26775A52DC6EA718CC1AA600AED05FBF35E084A6632F6072DA9AD13";
+        let mut expected = [0u8; 64];
+        let hex = format!("{}{}", expected_x, expected_y);
+        for i in 0..64 {
+            expected[i] = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+
+        let p = ProjectivePoint::mul_base(&d).to_affine().unwrap();
+        assert_eq!(p.to_bytes(), expected);
+        assert_eq!(AffinePoint::from_bytes(&expected), Some(p));
+    }
+
+    #[test]
+    fn test_point_multi_mul() {
+        // 多标量乘与逐个点乘求和结果一致
+        let g = ProjectivePoint::generator();
+        let k1 = scalar_from_hex("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B");
+        let k2 = scalar_from_hex("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8");
+        let p = g.mul(&k2);
+        let sum = ProjectivePoint::multi_mul_vartime(&[g, p], &[k1, k2]);
+        assert_eq!(sum, g.mul(&k1).add(&p.mul(&k2)));
+        assert_eq!(ProjectivePoint::multi_mul_vartime(&[], &[]).is_identity().unwrap_u8(), 1);
+    }
+
+    #[test]
+    fn test_point_from_x_mod_n() {
+        // 由x与y的奇偶恢复点：G的y为偶数
+        let g = AffinePoint::generator();
+        let x = Scalar::from_bytes(&g.x.to_bytes()).unwrap();
+        assert_eq!(AffinePoint::from_x_mod_n(&x, 0), Some(g));
+        assert_eq!(AffinePoint::from_x_mod_n(&x, 1), Some(ProjectivePoint::from(g).neg().to_affine().unwrap()));
+        assert!(AffinePoint::from_x_mod_n(&x, 4).is_none());
+
+        // x + n 超过p时无法恢复
+        let big = -Scalar::ONE;
+        assert!(AffinePoint::from_x_mod_n(&big, 2).is_none());
+    }
+
+    #[test]
+    fn test_point_mul_base_matches_mul() {
+        // 梳状表基点乘与通用点乘结果一致，覆盖边界标量
+        let g = ProjectivePoint::generator();
+        let mut scalars = vec![
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            scalar_from_hex("0000000000000000000000000000000000000000000000000000000000000008"),
+            scalar_from_hex("8888888888888888888888888888888888888888888888888888888888888888"),
+            scalar_from_hex("F777777777777777777777777777777777777777777777777777777777777777"),
+        ];
+        let mut k = scalar_from_hex("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B");
+        for _ in 0..8 {
+            k = k * k + Scalar::ONE;
+            scalars.push(k);
+            scalars.push(-k);
+        }
+        for k in &scalars {
+            assert_eq!(ProjectivePoint::mul_base(k), g.mul(k));
+            assert_eq!(g.mul_vartime(k), g.mul(k));
+        }
+    }
+
+    #[test]
+    fn test_point_mul_double_base() {
+        // [a]G + [b]P 与分别计算后相加一致
+        let g = ProjectivePoint::generator();
+        let p = g.mul(&scalar_from_hex("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8"));
+        let mut a = scalar_from_hex("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B");
+        let mut b = -Scalar::ONE;
+        for _ in 0..8 {
+            let expected = g.mul(&a).add(&p.mul(&b));
+            assert_eq!(ProjectivePoint::mul_double_base_vartime(&a, &b, &p), expected);
+            a = a * a + b;
+            b = b * a + Scalar::ONE;
+        }
+        assert_eq!(ProjectivePoint::mul_double_base_vartime(&Scalar::ZERO, &Scalar::ZERO, &p).is_identity().unwrap_u8(), 1);
+        assert_eq!(ProjectivePoint::mul_double_base_vartime(&Scalar::ONE, &-Scalar::ONE, &g).is_identity().unwrap_u8(), 1);
+    }
+
+    #[test]
+    fn test_point_mul_blinded_consistent() {
+        // 标量盲化与坐标随机化不改变结果：重复计算的仿射坐标完全相同
+        let g = ProjectivePoint::generator();
+        let p = g.mul_vartime(&scalar_from_hex("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8"));
+        for k in [Scalar::ONE, -Scalar::ONE, scalar_from_hex("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFF7203DF6B21C6052B53BBF40939D54122")] {
+            let expected = p.mul_vartime(&k).to_affine().unwrap();
+            for _ in 0..4 {
+                assert_eq!(p.mul(&k).to_affine(), Some(expected));
+                assert_eq!(ProjectivePoint::mul_base(&k), g.mul_vartime(&k));
+            }
+        }
+        assert_eq!(ProjectivePoint::IDENTITY.mul(&-Scalar::ONE).is_identity().unwrap_u8(), 1);
+        assert_eq!(p.mul(&Scalar::ZERO).is_identity().unwrap_u8(), 1);
+        assert_eq!(ProjectivePoint::mul_base(&Scalar::ZERO).is_identity().unwrap_u8(), 1);
+    }
+}
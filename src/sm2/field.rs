@@ -0,0 +1,232 @@
+// SM2素数域Fp上的蒙哥马利运算
+//
+// p = 2^256 - 2^224 - 2^96 + 2^64 - 1，按64位小端limb存储
+// p ≡ -1 (mod 2^64)，故 -p^-1 mod 2^64 = 1，蒙哥马利约减时 m 直接取最低limb
+
+use std::ops::{Add, Mul, Neg, Sub};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// 模数p
+const MODULUS: [u64; 4] = [0xFFFFFFFFFFFFFFFF, 0xFFFFFFFF00000000, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFEFFFFFFFF];
+/// R = 2^256 mod p
+const R: [u64; 4] = [0x0000000000000001, 0x00000000FFFFFFFF, 0x0000000000000000, 0x0000000100000000];
+/// R² = 2^512 mod p，用于转换到蒙哥马利形式
+const R2: [u64; 4] = [0x0000000200000003, 0x00000002FFFFFFFF, 0x0000000100000001, 0x0000000400000002];
+/// p - 2，费马小定理求逆的指数
+const P_MINUS_2: [u64; 4] = [0xFFFFFFFFFFFFFFFD, 0xFFFFFFFF00000000, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFEFFFFFFFF];
+/// (p + 1) / 4，p ≡ 3 (mod 4) 时用于开平方
+const P_PLUS_1_DIV_4: [u64; 4] = [0x4000000000000000, 0xFFFFFFFFC0000000, 0xFFFFFFFFFFFFFFFF, 0x3FFFFFFFBFFFFFFF];
+
+/// 有限域Fp上的元素（蒙哥马利形式，始终小于p）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldElement([u64; 4]);
+
+/// a + b + carry，返回 (和, 进位)
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// a - b - borrow，返回 (差, 借位掩码)，借位时掩码为全1
+#[inline(always)]
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub(b as u128 + (borrow >> 63) as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// a + b·c + carry，返回 (低64位, 高64位)
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// 对 [0, 2p) 内的数（含第5个limb的进位）减去p，结果落回 [0, p)
+#[inline(always)]
+fn sub_modulus(limbs: &[u64; 4], high: u64) -> [u64; 4] {
+    let (r0, borrow) = sbb(limbs[0], MODULUS[0], 0);
+    let (r1, borrow) = sbb(limbs[1], MODULUS[1], borrow);
+    let (r2, borrow) = sbb(limbs[2], MODULUS[2], borrow);
+    let (r3, borrow) = sbb(limbs[3], MODULUS[3], borrow);
+    let (_, borrow) = sbb(high, 0, borrow);
+
+    // 不够减时borrow为全1，保留原值
+    [
+        (limbs[0] & borrow) | (r0 & !borrow),
+        (limbs[1] & borrow) | (r1 & !borrow),
+        (limbs[2] & borrow) | (r2 & !borrow),
+        (limbs[3] & borrow) | (r3 & !borrow),
+    ]
+}
+
+/// 蒙哥马利乘法 a·b·R^-1 mod p（CIOS）
+#[inline(always)]
+fn montgomery_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 4];
+    let mut t4 = 0u64;
+    for &bi in b {
+        // t += a·b_i
+        let (t0, carry) = mac(t[0], a[0], bi, 0);
+        let (t1, carry) = mac(t[1], a[1], bi, carry);
+        let (t2, carry) = mac(t[2], a[2], bi, carry);
+        let (t3, carry) = mac(t[3], a[3], bi, carry);
+        let (t4_, t5) = adc(t4, carry, 0);
+
+        // m = t0·(-p^-1) mod 2^64 = t0，t = (t + m·p) / 2^64
+        let m = t0;
+        let (_, carry) = mac(t0, m, MODULUS[0], 0);
+        let (r0, carry) = mac(t1, m, MODULUS[1], carry);
+        let (r1, carry) = mac(t2, m, MODULUS[2], carry);
+        let (r2, carry) = mac(t3, m, MODULUS[3], carry);
+        let (r3, carry) = adc(t4_, carry, 0);
+        t = [r0, r1, r2, r3];
+        t4 = t5 + carry;
+    }
+    sub_modulus(&t, t4)
+}
+
+impl FieldElement {
+    pub const ZERO: Self = Self([0; 4]);
+    pub const ONE: Self = Self(R);
+
+    /// 从32字节大端编码解析，要求取值小于p
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = u64::from_be_bytes(bytes[24 - 8 * i..32 - 8 * i].try_into().unwrap());
+        }
+
+        // 固定时间比较 x < p
+        let (_, borrow) = sbb(limbs[0], MODULUS[0], 0);
+        let (_, borrow) = sbb(limbs[1], MODULUS[1], borrow);
+        let (_, borrow) = sbb(limbs[2], MODULUS[2], borrow);
+        let (_, borrow) = sbb(limbs[3], MODULUS[3], borrow);
+        if borrow == 0 {
+            return None;
+        }
+        Some(Self(montgomery_mul(&limbs, &R2)))
+    }
+
+    /// 输出32字节大端编码
+    pub fn to_bytes(self) -> [u8; 32] {
+        let limbs = montgomery_mul(&self.0, &[1, 0, 0, 0]);
+        let mut out = [0u8; 32];
+        for (i, limb) in limbs.iter().enumerate() {
+            out[24 - 8 * i..32 - 8 * i].copy_from_slice(&limb.to_be_bytes());
+        }
+        out
+    }
+
+    pub fn square(&self) -> Self {
+        Self(montgomery_mul(&self.0, &self.0))
+    }
+
+    pub fn double(&self) -> Self {
+        *self + *self
+    }
+
+    /// self^exp，指数为公开常量，按位平方-乘
+    fn pow(&self, exp: &[u64; 4]) -> Self {
+        let mut acc = Self::ONE;
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                acc = acc.square();
+                if (limb >> i) & 1 == 1 {
+                    acc = acc * *self;
+                }
+            }
+        }
+        acc
+    }
+
+    /// 乘法逆元 a^(p-2)，零元素没有逆元
+    pub fn invert(&self) -> Option<Self> {
+        if bool::from(self.is_zero()) {
+            None
+        } else {
+            Some(self.pow(&P_MINUS_2))
+        }
+    }
+
+    pub fn is_zero(&self) -> Choice {
+        self.ct_eq(&Self::ZERO)
+    }
+
+    /// 平方根 a^((p+1)/4)，a不是二次剩余时返回None
+    pub fn sqrt(&self) -> Option<Self> {
+        let root = self.pow(&P_PLUS_1_DIV_4);
+        if bool::from(root.square().ct_eq(self)) {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// 标准表示的最低位，用于压缩点编码中y的奇偶
+    pub fn is_odd(&self) -> Choice {
+        Choice::from(self.to_bytes()[31] & 1)
+    }
+}
+
+impl Add for FieldElement {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (r0, carry) = adc(self.0[0], rhs.0[0], 0);
+        let (r1, carry) = adc(self.0[1], rhs.0[1], carry);
+        let (r2, carry) = adc(self.0[2], rhs.0[2], carry);
+        let (r3, carry) = adc(self.0[3], rhs.0[3], carry);
+        Self(sub_modulus(&[r0, r1, r2, r3], carry))
+    }
+}
+
+impl Sub for FieldElement {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let (r0, borrow) = sbb(self.0[0], rhs.0[0], 0);
+        let (r1, borrow) = sbb(self.0[1], rhs.0[1], borrow);
+        let (r2, borrow) = sbb(self.0[2], rhs.0[2], borrow);
+        let (r3, borrow) = sbb(self.0[3], rhs.0[3], borrow);
+
+        // 有借位时加回p
+        let (r0, carry) = adc(r0, MODULUS[0] & borrow, 0);
+        let (r1, carry) = adc(r1, MODULUS[1] & borrow, carry);
+        let (r2, carry) = adc(r2, MODULUS[2] & borrow, carry);
+        let (r3, _) = adc(r3, MODULUS[3] & borrow, carry);
+        Self([r0, r1, r2, r3])
+    }
+}
+
+impl Mul for FieldElement {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self(montgomery_mul(&self.0, &rhs.0))
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl ConstantTimeEq for FieldElement {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[0].ct_eq(&other.0[0])
+            & self.0[1].ct_eq(&other.0[1])
+            & self.0[2].ct_eq(&other.0[2])
+            & self.0[3].ct_eq(&other.0[3])
+    }
+}
+
+impl ConditionallySelectable for FieldElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self([
+            u64::conditional_select(&a.0[0], &b.0[0], choice),
+            u64::conditional_select(&a.0[1], &b.0[1], choice),
+            u64::conditional_select(&a.0[2], &b.0[2], choice),
+            u64::conditional_select(&a.0[3], &b.0[3], choice),
+        ])
+    }
+}
@@ -0,0 +1,85 @@
+// SM2密钥派生函数（GB/T 32918.4 §5.4.3）
+//
+// KDF(Z, klen) = SM3(Z || ct_1) || SM3(Z || ct_2) || ...，ct为从1开始的32位大端计数器。
+// 带共享信息时按 ANSI X9.63 的方式把SharedInfo附加在计数器之后：SM3(Z || ct || SharedInfo)，
+// SharedInfo为空时与标准KDF一致。
+
+use sm3::{Digest, Sm3};
+
+/// KDF输出长度上限（字节）：(2^32 - 1) · 32
+pub const SM2_KDF_MAX_LEN: u64 = u32::MAX as u64 * 32;
+
+/// 派生klen字节的密钥，klen超过 [`SM2_KDF_MAX_LEN`] 时返回None
+pub fn sm2_kdf(z: &[u8], klen: usize) -> Option<Vec<u8>> {
+    if klen as u64 > SM2_KDF_MAX_LEN {
+        return None;
+    }
+    let mut out = vec![0u8; klen];
+    sm2_kdf_into(z, &[], &mut out).then_some(out)
+}
+
+/// 派生 out.len() 字节的密钥写入out，可附加共享信息
+///
+/// 长度超过 [`SM2_KDF_MAX_LEN`] 时返回false且不写入
+pub fn sm2_kdf_into(z: &[u8], shared_info: &[u8], out: &mut [u8]) -> bool {
+    Sm2KdfReader::new(z, shared_info).read(out)
+}
+
+/// 流式读取KDF输出，适合生成长密钥流
+#[derive(Clone)]
+pub struct Sm2KdfReader {
+    // 已吸收Z的SM3状态，每个分组从这里克隆
+    prefix: Sm3,
+    shared_info: Vec<u8>,
+    // 下一个分组使用的计数器
+    counter: u64,
+    block: [u8; 32],
+    // block中已读出的字节数
+    pos: usize,
+}
+
+impl Sm2KdfReader {
+    pub fn new(z: &[u8], shared_info: &[u8]) -> Self {
+        let mut prefix = Sm3::new();
+        prefix.update(z);
+        Self {
+            prefix,
+            shared_info: shared_info.to_vec(),
+            counter: 1,
+            block: [0u8; 32],
+            pos: 32,
+        }
+    }
+
+    /// 剩余可读字节数
+    pub fn remaining(&self) -> u64 {
+        (u32::MAX as u64 + 1 - self.counter) * 32 + (32 - self.pos) as u64
+    }
+
+    /// 读满out，剩余输出不足时返回false且不写入
+    pub fn read(&mut self, out: &mut [u8]) -> bool {
+        if out.len() as u64 > self.remaining() {
+            return false;
+        }
+        let mut written = 0;
+        while written < out.len() {
+            if self.pos == 32 {
+                self.next_block();
+            }
+            let n = (out.len() - written).min(32 - self.pos);
+            out[written..written + n].copy_from_slice(&self.block[self.pos..self.pos + n]);
+            self.pos += n;
+            written += n;
+        }
+        true
+    }
+
+    fn next_block(&mut self) {
+        let mut hasher = self.prefix.clone();
+        hasher.update((self.counter as u32).to_be_bytes());
+        hasher.update(&self.shared_info);
+        self.block.copy_from_slice(&hasher.finalize());
+        self.counter += 1;
+        self.pos = 0;
+    }
+}
@@ -0,0 +1,225 @@
+// SM2密钥交换协议（GB/T 32918.3）
+//
+// 发起方A与响应方B各持静态密钥对与临时密钥对：
+//   A → B：R_A
+//   B → A：R_B，（可选）S_B
+//   A → B：（可选）S_A
+// 双方得到相同的 K = KDF(x || y || Z_A || Z_B, klen)
+
+use super::curve::{AffinePoint, ProjectivePoint, Scalar};
+use super::{SM2_DEFAULT_ID, private_key_scalar, public_key_point, random_scalar, sm2_compute_z, sm2_kdf};
+use rand::rngs::OsRng;
+use sm3::{Digest, Sm3};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// 密钥交换中的角色
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sm2ExchangeRole {
+    /// 发起方A
+    Initiator,
+    /// 响应方B
+    Responder,
+}
+
+/// 密钥交换的一方
+pub struct Sm2KeyExchange {
+    role: Sm2ExchangeRole,
+    private_key: Scalar,
+    ephemeral_key: Scalar,
+    ephemeral_public_key: AffinePoint,
+    peer_public_key: AffinePoint,
+    z_self: [u8; 32],
+    z_peer: [u8; 32],
+}
+
+/// 密钥交换结果
+pub struct Sm2ExchangeResult {
+    /// 协商出的共享密钥
+    pub key: Vec<u8>,
+    /// 发给对方的确认值：发起方为S_A，响应方为S_B
+    pub confirmation: [u8; 32],
+    /// 期望收到的对方确认值：发起方为S_1（对应S_B），响应方为S_2（对应S_A）
+    expected_peer_confirmation: [u8; 32],
+}
+
+impl Sm2KeyExchange {
+    /// 使用默认用户身份标识创建，临时私钥随机生成
+    pub fn new(
+        role: Sm2ExchangeRole,
+        private_key: &[u8; 32],
+        public_key: &[u8; 64],
+        peer_public_key: &[u8; 64],
+    ) -> Option<Self> {
+        Self::with_ids(role, private_key, public_key, SM2_DEFAULT_ID, peer_public_key, SM2_DEFAULT_ID)
+    }
+
+    /// 指定双方用户身份标识创建，临时私钥随机生成；ID超长时返回None
+    pub fn with_ids(
+        role: Sm2ExchangeRole,
+        private_key: &[u8; 32],
+        public_key: &[u8; 64],
+        id: &[u8],
+        peer_public_key: &[u8; 64],
+        peer_id: &[u8],
+    ) -> Option<Self> {
+        let mut ephemeral_key = random_scalar(&mut OsRng).to_bytes();
+        let exchange =
+            Self::with_ephemeral_key(role, private_key, public_key, id, peer_public_key, peer_id, &ephemeral_key);
+        ephemeral_key.zeroize();
+        exchange
+    }
+
+    /// 指定临时私钥创建，用于已知答案测试
+    pub fn with_ephemeral_key(
+        role: Sm2ExchangeRole,
+        private_key: &[u8; 32],
+        public_key: &[u8; 64],
+        id: &[u8],
+        peer_public_key: &[u8; 64],
+        peer_id: &[u8],
+        ephemeral_key: &[u8; 32],
+    ) -> Option<Self> {
+        let private_key = private_key_scalar(private_key)?;
+        let own_point = public_key_point(public_key)?;
+        let peer_point = public_key_point(peer_public_key)?;
+
+        // 公钥须与私钥匹配
+        if ProjectivePoint::mul_base(&private_key) != ProjectivePoint::from(own_point) {
+            return None;
+        }
+
+        let ephemeral_key = Scalar::from_bytes(ephemeral_key)?;
+        if bool::from(ephemeral_key.is_zero()) {
+            return None;
+        }
+        let ephemeral_public_key = ProjectivePoint::mul_base(&ephemeral_key).to_affine()?;
+
+        Some(Self {
+            role,
+            private_key,
+            ephemeral_key,
+            ephemeral_public_key,
+            peer_public_key: peer_point,
+            z_self: sm2_compute_z(public_key, id)?,
+            z_peer: sm2_compute_z(peer_public_key, peer_id)?,
+        })
+    }
+
+    /// 本方角色
+    pub fn role(&self) -> Sm2ExchangeRole {
+        self.role
+    }
+
+    /// 本方临时公钥R（x || y），发送给对方
+    pub fn ephemeral_public_key(&self) -> [u8; 64] {
+        self.ephemeral_public_key.to_bytes()
+    }
+
+    /// 收到对方临时公钥后计算klen字节的共享密钥与确认值
+    ///
+    /// 对方临时公钥不在曲线上、共享点为无穷远点或klen超过KDF上限时返回None
+    pub fn compute(&self, peer_ephemeral_public_key: &[u8; 64], klen: usize) -> Option<Sm2ExchangeResult> {
+        let peer_ephemeral = public_key_point(peer_ephemeral_public_key)?;
+
+        // t = (d + x̄·r) mod n
+        let x_bar = Scalar::from_bytes(&truncate_x(&self.ephemeral_public_key))?;
+        let mut t = self.private_key + x_bar * self.ephemeral_key;
+
+        // U/V = [h·t](P_peer + [x̄_peer]R_peer)，h = 1
+        let peer_x_bar = Scalar::from_bytes(&truncate_x(&peer_ephemeral))?;
+        let sum = ProjectivePoint::from(self.peer_public_key)
+            .add(&ProjectivePoint::from(peer_ephemeral).mul(&peer_x_bar));
+        let shared = sum.mul(&t).to_affine();
+        t.zeroize();
+        let shared = shared?;
+        let mut shared_x = shared.x.to_bytes();
+        let mut shared_y = shared.y.to_bytes();
+
+        // Z_A、Z_B以及R_A、R_B始终按发起方在前的顺序参与计算
+        let own_ephemeral = self.ephemeral_public_key.to_bytes();
+        let (z_a, z_b, r_a, r_b) = match self.role {
+            Sm2ExchangeRole::Initiator => (&self.z_self, &self.z_peer, &own_ephemeral, peer_ephemeral_public_key),
+            Sm2ExchangeRole::Responder => (&self.z_peer, &self.z_self, peer_ephemeral_public_key, &own_ephemeral),
+        };
+
+        // K = KDF(x || y || Z_A || Z_B, klen)
+        let mut kdf_input = [0u8; 128];
+        kdf_input[..32].copy_from_slice(&shared_x);
+        kdf_input[32..64].copy_from_slice(&shared_y);
+        kdf_input[64..96].copy_from_slice(z_a);
+        kdf_input[96..].copy_from_slice(z_b);
+        let key = sm2_kdf(&kdf_input, klen);
+        kdf_input.zeroize();
+        let Some(key) = key else {
+            shared_x.zeroize();
+            shared_y.zeroize();
+            return None;
+        };
+
+        // Hash(x || Z_A || Z_B || x1 || y1 || x2 || y2)
+        let mut hasher = Sm3::new();
+        hasher.update(shared_x);
+        hasher.update(z_a);
+        hasher.update(z_b);
+        hasher.update(r_a);
+        hasher.update(r_b);
+        let inner: [u8; 32] = hasher.finalize().into();
+
+        // S_B/S_1 前缀0x02，S_A/S_2 前缀0x03
+        let s_b = confirmation_hash(0x02, &shared_y, &inner);
+        let s_a = confirmation_hash(0x03, &shared_y, &inner);
+        shared_x.zeroize();
+        shared_y.zeroize();
+        let (confirmation, expected_peer_confirmation) = match self.role {
+            Sm2ExchangeRole::Initiator => (s_a, s_b),
+            Sm2ExchangeRole::Responder => (s_b, s_a),
+        };
+
+        Some(Sm2ExchangeResult {
+            key,
+            confirmation,
+            expected_peer_confirmation,
+        })
+    }
+}
+
+/// 静态私钥与临时私钥在释放时清零
+impl Drop for Sm2KeyExchange {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+        self.ephemeral_key.zeroize();
+    }
+}
+
+impl Sm2ExchangeResult {
+    /// 固定时间校验对方发来的确认值（发起方校验S_B，响应方校验S_A）
+    pub fn verify_peer_confirmation(&self, peer_confirmation: &[u8; 32]) -> bool {
+        bool::from(self.expected_peer_confirmation.ct_eq(peer_confirmation))
+    }
+}
+
+/// 共享密钥在释放时清零
+impl Drop for Sm2ExchangeResult {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// x̄ = 2^w + (x & (2^w - 1))，w = 127
+fn truncate_x(point: &AffinePoint) -> [u8; 32] {
+    let x = point.x.to_bytes();
+    let mut out = [0u8; 32];
+    out[16..].copy_from_slice(&x[16..]);
+    out[16] |= 0x80;
+    out
+}
+
+/// Hash(prefix || y || inner)
+fn confirmation_hash(prefix: u8, y: &[u8; 32], inner: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sm3::new();
+    hasher.update([prefix]);
+    hasher.update(y);
+    hasher.update(inner);
+    hasher.finalize().into()
+}
@@ -1,175 +1,582 @@
 // SM2算法主模块
 
-use rand::Rng;
+mod batch;
+mod collaborative;
+mod curve;
+mod field;
+mod kdf;
+mod key_exchange;
+mod nonce;
+mod pkcs8;
+mod public_key;
+mod types;
+
+use crate::asn1::{DerReader, TAG_OCTET_STRING, encode_sequence, encode_tlv, encode_unsigned_integer};
+use curve::{AffinePoint, ProjectivePoint, Scalar};
+use nonce::NonceGenerator;
+use subtle::ConstantTimeEq;
+
+pub use batch::Sm2BatchVerifier;
+pub use collaborative::{
+    Sm2CoDecryptRequest, Sm2CoDecryptResponse, Sm2CoDecryptSession, Sm2CoSignClient, Sm2CoSignClientKeygen,
+    Sm2CoSignClientSession, Sm2CoSignKeygenRequest, Sm2CoSignKeygenResponse, Sm2CoSignRequest, Sm2CoSignResponse,
+    Sm2CoSignServer,
+};
+pub use curve::{SM2_A, SM2_B, SM2_GX, SM2_GY, SM2_N, SM2_P};
+pub use kdf::{SM2_KDF_MAX_LEN, Sm2KdfReader, sm2_kdf, sm2_kdf_into};
+pub use key_exchange::{Sm2ExchangeResult, Sm2ExchangeRole, Sm2KeyExchange};
+pub use public_key::{Sm2PointFormat, Sm2PublicKey};
+pub use types::{Sm2Ciphertext, Sm2PrivateKey, Sm2Signature};
+use rand::RngCore;
 use rand::rngs::OsRng;
 use sm3::{Digest, Sm3};
 
 /// 生成SM2密钥对
+///
+/// 私钥d在 [1, n-2] 中均匀选取，公钥 P = [d]G，以 x || y 形式返回
 pub fn sm2_generate_keypair() -> ([u8; 32], [u8; 64]) {
+    let mut rng = OsRng;
     let mut private_key = [0u8; 32];
-    let mut public_key = [0u8; 64];
-    
-    // 生成随机私钥
-    let mut rng = rand::thread_rng();
-    rng.fill(&mut private_key[..]);
     
-    // 生成公钥，基于私钥（简化实现）
-    // 实际应用中，公钥是私钥通过椭圆曲线算法计算得到的
-    for i in 0..32 {
-        public_key[i] = private_key[i] ^ 0x55;
-        public_key[i + 32] = private_key[i] ^ 0xAA;
+    // 拒绝采样：落在 [1, n-2] 之外的候选值直接丢弃重取
+    loop {
+        rng.fill_bytes(&mut private_key);
+        if let Some(public_key) = sm2_derive_public_key(&private_key) {
+            return (private_key, public_key);
+        }
     }
-    
-    (private_key, public_key)
 }
 
-/// SM2签名
+/// 由私钥计算公钥 P = [d]G，私钥不在 [1, n-2] 范围内时返回None
+pub fn sm2_derive_public_key(private_key: &[u8; 32]) -> Option<[u8; 64]> {
+    let d = private_key_scalar(private_key)?;
+    let point = ProjectivePoint::mul_base(&d).to_affine()?;
+    Some(point.to_bytes())
+}
+
+/// 解析私钥并检查 1 <= d <= n-2（签名需要 1 + d 可逆）
+fn private_key_scalar(private_key: &[u8; 32]) -> Option<Scalar> {
+    let d = Scalar::from_bytes(private_key)?;
+    if bool::from(d.is_zero() | (d + Scalar::ONE).is_zero()) {
+        return None;
+    }
+    Some(d)
+}
+
+/// 解析公钥并做完整校验：坐标在 [0, p-1]、点在曲线上且 [n]Q = O
+///
+/// x || y 编码无法表示无穷远点（(0, 0) 不在曲线上）
+fn public_key_point(public_key: &[u8; 64]) -> Option<AffinePoint> {
+    let point = AffinePoint::from_bytes(public_key)?;
+    if point.has_order_n() { Some(point) } else { None }
+}
+
+/// 校验公钥是否为有效的SM2公钥
+pub fn sm2_validate_public_key(public_key: &[u8; 64]) -> bool {
+    public_key_point(public_key).is_some()
+}
+
+/// 校验私钥是否在 [1, n-2] 范围内
+pub fn sm2_validate_private_key(private_key: &[u8; 32]) -> bool {
+    private_key_scalar(private_key).is_some()
+}
+
+/// 成对一致性检查：私钥、公钥各自有效且 [d]G 等于给定公钥
+pub fn sm2_validate_keypair(private_key: &[u8; 32], public_key: &[u8; 64]) -> bool {
+    let (Some(d), Some(point)) = (private_key_scalar(private_key), public_key_point(public_key)) else {
+        return false;
+    };
+    ProjectivePoint::mul_base(&d) == ProjectivePoint::from(point)
+}
+
+/// SM2默认用户身份标识（GB/T 35276）
+pub const SM2_DEFAULT_ID: &[u8] = b"1234567812345678";
+
+/// 用户身份标识的最大字节数，ENTL为两字节的比特长度
+pub const SM2_MAX_ID_LEN: usize = 8191;
+
+/// SM2签名（GB/T 32918.2），使用默认用户身份标识
+///
+/// e = SM3(Z_A || M)，返回 r || s；私钥不在 [1, n-2] 范围内时返回全零签名
 pub fn sm2_sign(private_key: &[u8; 32], message: &[u8]) -> [u8; 64] {
-    let mut signature = [0u8; 64];
+    sm2_sign_with_id(private_key, SM2_DEFAULT_ID, message)
+}
+
+/// 使用指定用户身份标识的SM2签名，私钥或ID无效时返回全零签名
+pub fn sm2_sign_with_id(private_key: &[u8; 32], id: &[u8], message: &[u8]) -> [u8; 64] {
+    sm2_sign_with_nonce_mode(private_key, id, message, Sm2NonceMode::Random)
+}
+
+/// 签名随机数k的生成方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sm2NonceMode {
+    /// 每次从系统随机源选取
+    Random,
+    /// 由私钥与e经HMAC-SM3 DRBG确定性派生（参照RFC 6979），同一输入总得到同一签名
+    Deterministic,
+    /// 确定性派生时再混入32字节新鲜随机数，随机源失效时仍不会重复k
+    Hedged,
+}
+
+/// 确定性SM2签名，使用默认用户身份标识
+pub fn sm2_sign_deterministic(private_key: &[u8; 32], message: &[u8]) -> [u8; 64] {
+    sm2_sign_with_nonce_mode(private_key, SM2_DEFAULT_ID, message, Sm2NonceMode::Deterministic)
+}
+
+/// 按指定随机数生成方式签名，私钥或ID无效时返回全零签名
+pub fn sm2_sign_with_nonce_mode(private_key: &[u8; 32], id: &[u8], message: &[u8], mode: Sm2NonceMode) -> [u8; 64] {
+    let Some(d) = private_key_scalar(private_key) else {
+        return [0u8; 64];
+    };
+    let public_key = ProjectivePoint::mul_base(&d).to_affine().unwrap().to_bytes();
     
-    // 计算消息的SM3哈希
-    let mut hasher = Sm3::new();
-    hasher.update(message);
-    let digest = hasher.finalize();
+    // 计算 e = SM3(Z_A || M)
+    let Some(z) = sm2_compute_z(&public_key, id) else {
+        return [0u8; 64];
+    };
+    let e = message_digest(&z, message);
     
-    // 生成签名（基于私钥和哈希值）
-    for i in 0..32 {
-        signature[i] = digest[i] ^ private_key[i];
-        signature[i + 32] = digest[i] ^ private_key[(i + 16) % 32];
-    }
+    sign_prehashed(&d, &e, mode).0
+}
+
+/// 对摘要e签名，按mode生成k直到得到有效签名，同时返回恢复标识
+fn sign_prehashed(d: &Scalar, e: &[u8; 32], mode: Sm2NonceMode) -> ([u8; 64], u8) {
+    let mut rng = OsRng;
+    let mut generator = match mode {
+        Sm2NonceMode::Random => None,
+        Sm2NonceMode::Deterministic => Some(NonceGenerator::new(d, e, &[])),
+        Sm2NonceMode::Hedged => {
+            let mut extra = [0u8; 32];
+            rng.fill_bytes(&mut extra);
+            Some(NonceGenerator::new(d, e, &extra))
+        }
+    };
     
-    signature
+    loop {
+        let k = match generator.as_mut() {
+            Some(generator) => generator.next_scalar(),
+            None => random_scalar(&mut rng),
+        };
+        if let Some(result) = sign_with_nonce(d, e, &k) {
+            return result;
+        }
+    }
 }
 
-/// SM2验签
+/// SM2验签（GB/T 32918.2），使用默认用户身份标识
 pub fn sm2_verify(public_key: &[u8; 64], message: &[u8], signature: &[u8; 64]) -> bool {
-    // 计算消息的SM3哈希
-    let mut hasher = Sm3::new();
-    hasher.update(message);
-    let digest = hasher.finalize();
-    
-    // 验证签名
-    // 对于有效的签名，应该满足一定的条件
-    let mut valid = true;
-    for i in 0..32 {
-        // 检查签名是否与哈希值相关
-        let expected_r = digest[i] ^ signature[i];
-        let expected_s = digest[i] ^ signature[i + 32];
-        
-        // 验证：检查计算出的值是否与公钥的某些部分匹配
-        // 由于公钥是基于私钥生成的，我们可以通过公钥反推私钥的某些信息
-        let expected_private_r = public_key[i] ^ 0x55;
-        let expected_private_s = public_key[(i + 16) % 32] ^ 0x55;
-        
-        if expected_r != expected_private_r || expected_s != expected_private_s {
-            valid = false;
-            break;
-        }
+    sm2_verify_with_id(public_key, SM2_DEFAULT_ID, message, signature)
+}
+
+/// 使用指定用户身份标识的SM2验签
+pub fn sm2_verify_with_id(public_key: &[u8; 64], id: &[u8], message: &[u8], signature: &[u8; 64]) -> bool {
+    let Some(point) = public_key_point(public_key) else {
+        return false;
+    };
+    
+    // 计算 e = SM3(Z_A || M)
+    let Some(z) = sm2_compute_z(public_key, id) else {
+        return false;
+    };
+    let e = message_digest(&z, message);
+    
+    verify_with_point(&point, &e, signature)
+}
+
+/// 计算待签名摘要 e = SM3(Z_A || M)，供签名服务只接收摘要时在调用方预先计算
+///
+/// 公钥不在曲线上或ID超长时返回None
+pub fn sm2_message_digest(public_key: &[u8; 64], id: &[u8], message: &[u8]) -> Option<[u8; 32]> {
+    public_key_point(public_key)?;
+    let z = sm2_compute_z(public_key, id)?;
+    Some(message_digest(&z, message))
+}
+
+/// 对预先计算好的摘要e签名，私钥无效时返回全零签名
+pub fn sm2_sign_digest(private_key: &[u8; 32], digest: &[u8; 32]) -> [u8; 64] {
+    sm2_sign_digest_with_nonce_mode(private_key, digest, Sm2NonceMode::Random)
+}
+
+/// 按指定随机数生成方式对摘要e签名，私钥无效时返回全零签名
+pub fn sm2_sign_digest_with_nonce_mode(private_key: &[u8; 32], digest: &[u8; 32], mode: Sm2NonceMode) -> [u8; 64] {
+    let Some(d) = private_key_scalar(private_key) else {
+        return [0u8; 64];
+    };
+    sign_prehashed(&d, digest, mode).0
+}
+
+/// 验证摘要e上的签名
+pub fn sm2_verify_digest(public_key: &[u8; 64], digest: &[u8; 32], signature: &[u8; 64]) -> bool {
+    let Some(point) = public_key_point(public_key) else {
+        return false;
+    };
+    verify_with_point(&point, digest, signature)
+}
+
+/// 对摘要e签名并返回恢复标识，私钥无效时返回全零签名
+///
+/// 恢复标识第0位为 [k]G 的y坐标奇偶，第1位表示其x坐标不小于n
+pub fn sm2_sign_digest_recoverable(private_key: &[u8; 32], digest: &[u8; 32]) -> ([u8; 64], u8) {
+    let Some(d) = private_key_scalar(private_key) else {
+        return ([0u8; 64], 0);
+    };
+    sign_prehashed(&d, digest, Sm2NonceMode::Random)
+}
+
+/// 由摘要e、签名与恢复标识恢复签名者公钥
+///
+/// [s]G + [t]P = R，故 P = [t^-1](R - [s]G)。e依赖Z_A，调用方须已知e（例如链上交易直接签名摘要）。
+/// 签名不在范围内、恢复标识无效或恢复出无穷远点时返回None
+pub fn sm2_recover_public_key(digest: &[u8; 32], signature: &[u8; 64], recovery_id: u8) -> Option<[u8; 64]> {
+    // r, s ∈ [1, n-1]，t = (r + s) mod n ≠ 0
+    let (r, s) = signature_scalars(signature)?;
+    let t = r + s;
+    if bool::from(t.is_zero()) {
+        return None;
     }
     
-    valid
+    // R的x坐标 x1 ≡ r - e (mod n)
+    let x = r - Scalar::from_bytes_reduced(digest);
+    let r_point = AffinePoint::from_x_mod_n(&x, recovery_id)?;
+    
+    // P = [-s·t^-1]G + [t^-1]R
+    let t_inv = t.invert()?;
+    let point = ProjectivePoint::mul_double_base_vartime(&(-s * t_inv), &t_inv, &ProjectivePoint::from(r_point));
+    Some(point.to_affine()?.to_bytes())
 }
 
-/// SM2加密
-pub fn sm2_encrypt(public_key: &[u8; 64], message: &[u8]) -> Vec<u8> {
-    // 从公钥中提取与私钥相关的部分
-    // 由于公钥是私钥通过异或0x55生成的，我们可以通过异或0x55来恢复私钥的相关部分
-    let mut private_key_part = [0u8; 32];
-    for i in 0..32 {
-        private_key_part[i] = public_key[i] ^ 0x55;
+/// 将 r || s 编码为 GM/T 0009 的 SM2Signature：SEQUENCE { r INTEGER, s INTEGER }
+///
+/// r、s 不在 [1, n-1] 中时返回None
+pub fn sm2_signature_to_der(signature: &[u8; 64]) -> Option<Vec<u8>> {
+    signature_scalars(signature)?;
+    Some(encode_sequence(&[
+        &encode_unsigned_integer(&signature[..32]),
+        &encode_unsigned_integer(&signature[32..]),
+    ]))
+}
+
+/// 严格解析DER编码的SM2Signature，返回 r || s
+///
+/// 拒绝非最短编码、负数、尾随数据以及不在 [1, n-1] 中的r、s
+pub fn sm2_signature_from_der(der: &[u8]) -> Option<[u8; 64]> {
+    let mut outer = DerReader::new(der);
+    let mut seq = outer.read_sequence()?;
+    outer.finish()?;
+    let r = seq.read_uint256()?;
+    let s = seq.read_uint256()?;
+    seq.finish()?;
+    
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&r);
+    signature[32..].copy_from_slice(&s);
+    signature_scalars(&signature)?;
+    Some(signature)
+}
+
+/// 用给定的随机数k生成签名及恢复标识，r = 0、r + k = n 或 s = 0 时返回None
+fn sign_with_nonce(d: &Scalar, e: &[u8; 32], k: &Scalar) -> Option<([u8; 64], u8)> {
+    // (x1, y1) = [k]G
+    let kg = ProjectivePoint::mul_base(k).to_affine()?;
+    
+    // r = (e + x1) mod n
+    let r = Scalar::from_bytes_reduced(e) + Scalar::from_bytes_reduced(&kg.x.to_bytes());
+    if bool::from(r.is_zero() | (r + *k).is_zero()) {
+        return None;
     }
     
-    // 计算Z值（使用恢复的私钥部分）
-    let z = calculate_z(&private_key_part);
+    // s = (1 + d)^-1 · (k - r·d) mod n
+    let s = (Scalar::ONE + *d).invert()? * (*k - r * *d);
+    if bool::from(s.is_zero()) {
+        return None;
+    }
     
-    // 计算密钥派生
-    let k = sm2_kdf(&z, message.len());
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&r.to_bytes());
+    signature[32..].copy_from_slice(&s.to_bytes());
     
-    // 简单加密：使用异或操作（实际应用中需要使用RustCrypto的完整实现）
-    let mut ciphertext = Vec::new();
+    // x1 ≥ n 时 Scalar::from_bytes 拒绝
+    let x_overflow = Scalar::from_bytes(&kg.x.to_bytes()).is_none() as u8;
+    let recovery_id = kg.y.is_odd().unwrap_u8() | (x_overflow << 1);
+    Some((signature, recovery_id))
+}
+
+/// 用公钥点验证摘要e上的签名
+fn verify_with_point(point: &AffinePoint, e: &[u8; 32], signature: &[u8; 64]) -> bool {
+    // r, s ∈ [1, n-1]
+    let Some((r, s)) = signature_scalars(signature) else {
+        return false;
+    };
+    
+    // t = (r + s) mod n，t ≠ 0
+    let t = r + s;
+    if bool::from(t.is_zero()) {
+        return false;
+    }
     
-    // 添加简单的校验和（消息长度的低8位）
-    ciphertext.push(message.len() as u8);
+    // (x1, y1) = [s]G + [t]P_A
+    let sum = ProjectivePoint::mul_double_base_vartime(&s, &t, &ProjectivePoint::from(*point));
+    let Some(sum) = sum.to_affine() else {
+        return false;
+    };
     
-    // 加密消息
-    for (i, &byte) in message.iter().enumerate() {
-        ciphertext.push(byte ^ k[i % k.len()]);
+    // R = (e + x1) mod n，检查 R = r
+    let expected = Scalar::from_bytes_reduced(e) + Scalar::from_bytes_reduced(&sum.x.to_bytes());
+    bool::from(expected.ct_eq(&r))
+}
+
+/// 解析签名中的r、s，任一不在 [1, n-1] 中时返回None
+fn signature_scalars(signature: &[u8; 64]) -> Option<(Scalar, Scalar)> {
+    let r = Scalar::from_bytes(signature[..32].try_into().unwrap())?;
+    let s = Scalar::from_bytes(signature[32..].try_into().unwrap())?;
+    if bool::from(r.is_zero() | s.is_zero()) {
+        return None;
+    }
+    Some((r, s))
+}
+
+/// 在 [1, n-1] 中均匀选取随机数
+fn random_scalar(rng: &mut OsRng) -> Scalar {
+    let mut bytes = [0u8; 32];
+    loop {
+        rng.fill_bytes(&mut bytes);
+        if let Some(k) = Scalar::from_bytes(&bytes)
+            && !bool::from(k.is_zero())
+        {
+            return k;
+        }
+    }
+}
+
+/// 计算 e = SM3(Z || M)
+fn message_digest(z: &[u8; 32], message: &[u8]) -> [u8; 32] {
+    let mut hasher = Sm3::new();
+    hasher.update(z);
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// SM2公钥加密（GB/T 32918.4），密文格式为 C1 || C3 || C2
+///
+/// C1 = 04 || x1 || y1，C3 = SM3(x2 || M || y2)，C2 = M ⊕ KDF(x2 || y2, klen)；
+/// 公钥无效或消息为空时返回空Vec
+pub fn sm2_encrypt(public_key: &[u8; 64], message: &[u8]) -> Vec<u8> {
+    let Some(point) = public_key_point(public_key) else {
+        return Vec::new();
+    };
+    if message.is_empty() {
+        return Vec::new();
     }
+    let point = ProjectivePoint::from(point);
     
-    ciphertext
+    let mut rng = OsRng;
+    loop {
+        // C1 = [k]G
+        let k = random_scalar(&mut rng);
+        let c1 = ProjectivePoint::mul_base(&k).to_affine().unwrap();
+        
+        // (x2, y2) = [k]P_B，余因子h = 1，无需额外检查[h]P_B
+        let Some(shared) = point.mul(&k).to_affine() else {
+            continue;
+        };
+        let shared = shared.to_bytes();
+        
+        // t = KDF(x2 || y2, klen)直接写入C2的位置，t全零时重新选取k
+        let mut ciphertext = vec![0u8; 1 + 64 + 32 + message.len()];
+        if !sm2_kdf_into(&shared, &[], &mut ciphertext[97..]) {
+            return Vec::new();
+        }
+        if ciphertext[97..].iter().all(|&b| b == 0) {
+            continue;
+        }
+        
+        // C2 = M ⊕ t
+        for (c, m) in ciphertext[97..].iter_mut().zip(message) {
+            *c ^= m;
+        }
+        ciphertext[0] = 0x04;
+        ciphertext[1..65].copy_from_slice(&c1.to_bytes());
+        ciphertext[65..97].copy_from_slice(&ciphertext_hash(&shared, message));
+        return ciphertext;
+    }
 }
 
-/// SM2解密
+/// SM2公钥解密（GB/T 32918.4），输入为 C1 || C3 || C2 格式的密文
+///
+/// C1不在曲线上、t全零或C3校验失败时返回None
 pub fn sm2_decrypt(private_key: &[u8; 32], ciphertext: &[u8]) -> Option<Vec<u8>> {
-    // 检查密文是否为空或长度小于1（至少需要包含校验和）
-    if ciphertext.len() < 1 {
+    // 至少包含 C1(65) + C3(32) + 1字节C2
+    if ciphertext.len() < 1 + 64 + 32 + 1 || ciphertext[0] != 0x04 {
         return None;
     }
+    let d = private_key_scalar(private_key)?;
     
-    // 提取校验和（消息长度的低8位）
-    let expected_len = ciphertext[0] as usize;
+    // 检查C1在曲线上
+    let c1 = AffinePoint::from_bytes(ciphertext[1..65].try_into().unwrap())?;
+    let c3 = &ciphertext[65..97];
+    let c2 = &ciphertext[97..];
     
-    // 检查密文长度是否合理
-    if ciphertext.len() - 1 < expected_len {
+    // (x2, y2) = [d]C1
+    let shared = ProjectivePoint::from(c1).mul(&d).to_affine()?.to_bytes();
+    recover_plaintext(&shared, c3, c2)
+}
+
+/// 由共享点 (x2, y2) 还原明文并校验C3
+fn recover_plaintext(shared: &[u8; 64], c3: &[u8], c2: &[u8]) -> Option<Vec<u8>> {
+    // t = KDF(x2 || y2, klen)，t全零时解密失败
+    let mut plaintext = vec![0u8; c2.len()];
+    if !sm2_kdf_into(shared, &[], &mut plaintext) || plaintext.iter().all(|&b| b == 0) {
         return None;
     }
     
-    // 计算Z值（使用私钥）
-    let z = calculate_z(private_key);
+    // M' = C2 ⊕ t
+    for (m, c) in plaintext.iter_mut().zip(c2) {
+        *m ^= c;
+    }
     
-    // 计算密钥派生
-    let k = sm2_kdf(&z, expected_len);
+    // 固定时间比较 u = SM3(x2 || M' || y2) 与 C3
+    let u = ciphertext_hash(shared, &plaintext);
+    if !bool::from(u[..].ct_eq(c3)) {
+        return None;
+    }
     
-    // 简单解密：使用异或操作（实际应用中需要使用RustCrypto的完整实现）
-    let mut plaintext = Vec::new();
-    for (i, &byte) in ciphertext[1..].iter().enumerate() {
-        if i >= expected_len {
-            break;
-        }
-        plaintext.push(byte ^ k[i % k.len()]);
+    Some(plaintext)
+}
+
+/// SM2密文编码格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sm2CiphertextFormat {
+    /// GB/T 32918.4-2016：04 || x1 || y1 || C3 || C2
+    C1C3C2,
+    /// 2010版草案：04 || x1 || y1 || C2 || C3
+    C1C2C3,
+    /// 省略04前缀的C1C3C2：x1 || y1 || C3 || C2
+    C1C3C2Raw,
+    /// 省略04前缀的C1C2C3：x1 || y1 || C2 || C3
+    C1C2C3Raw,
+    /// GM/T 0009：SEQUENCE { x INTEGER, y INTEGER, hash OCTET STRING, ciphertext OCTET STRING }
+    Der,
+}
+
+/// 以指定格式输出SM2加密结果，公钥无效或消息为空时返回空Vec
+pub fn sm2_encrypt_with_format(public_key: &[u8; 64], message: &[u8], format: Sm2CiphertextFormat) -> Vec<u8> {
+    let ciphertext = sm2_encrypt(public_key, message);
+    if ciphertext.is_empty() {
+        return ciphertext;
     }
+    sm2_convert_ciphertext(&ciphertext, Sm2CiphertextFormat::C1C3C2, format).unwrap_or_default()
+}
+
+/// 解密指定格式的SM2密文
+pub fn sm2_decrypt_with_format(private_key: &[u8; 32], ciphertext: &[u8], format: Sm2CiphertextFormat) -> Option<Vec<u8>> {
+    let ciphertext = sm2_convert_ciphertext(ciphertext, format, Sm2CiphertextFormat::C1C3C2)?;
+    sm2_decrypt(private_key, &ciphertext)
+}
+
+/// 在不同SM2密文格式之间转换，只做结构转换，不校验C1是否在曲线上
+pub fn sm2_convert_ciphertext(ciphertext: &[u8], from: Sm2CiphertextFormat, to: Sm2CiphertextFormat) -> Option<Vec<u8>> {
+    let (c1, c3, c2) = split_ciphertext(ciphertext, from)?;
+    Some(join_ciphertext(&c1, &c3, c2, to))
+}
+
+/// 按格式拆分密文为 (C1, C3, C2)，C1为 x1 || y1
+fn split_ciphertext(ciphertext: &[u8], format: Sm2CiphertextFormat) -> Option<([u8; 64], [u8; 32], &[u8])> {
+    use Sm2CiphertextFormat::*;
+    
+    let body = match format {
+        C1C3C2 | C1C2C3 => match ciphertext.split_first()? {
+            (0x04, rest) => rest,
+            _ => return None,
+        },
+        C1C3C2Raw | C1C2C3Raw => ciphertext,
+        Der => {
+            let mut outer = DerReader::new(ciphertext);
+            let mut seq = outer.read_sequence()?;
+            outer.finish()?;
+            let x = seq.read_uint256()?;
+            let y = seq.read_uint256()?;
+            let c3: [u8; 32] = seq.read(TAG_OCTET_STRING)?.try_into().ok()?;
+            let c2 = seq.read(TAG_OCTET_STRING)?;
+            seq.finish()?;
+            
+            let mut c1 = [0u8; 64];
+            c1[..32].copy_from_slice(&x);
+            c1[32..].copy_from_slice(&y);
+            return Some((c1, c3, c2));
+        }
+    };
     
-    // 验证解密后的消息长度是否与预期一致
-    if plaintext.len() != expected_len {
+    // C1(64) + C3(32) + 至少1字节C2
+    if body.len() < 64 + 32 + 1 {
         return None;
     }
+    let c1: [u8; 64] = body[..64].try_into().unwrap();
+    let rest = &body[64..];
+    let (c3, c2) = match format {
+        C1C3C2 | C1C3C2Raw => (&rest[..32], &rest[32..]),
+        _ => (&rest[rest.len() - 32..], &rest[..rest.len() - 32]),
+    };
+    Some((c1, c3.try_into().unwrap(), c2))
+}
+
+/// 按格式拼接 (C1, C3, C2)
+fn join_ciphertext(c1: &[u8; 64], c3: &[u8; 32], c2: &[u8], format: Sm2CiphertextFormat) -> Vec<u8> {
+    use Sm2CiphertextFormat::*;
     
-    Some(plaintext)
+    let mut out = Vec::with_capacity(1 + 64 + 32 + c2.len());
+    match format {
+        C1C3C2 | C1C2C3 => out.push(0x04),
+        C1C3C2Raw | C1C2C3Raw => {}
+        Der => {
+            return encode_sequence(&[
+                &encode_unsigned_integer(&c1[..32]),
+                &encode_unsigned_integer(&c1[32..]),
+                &encode_tlv(TAG_OCTET_STRING, c3),
+                &encode_tlv(TAG_OCTET_STRING, c2),
+            ]);
+        }
+    }
+    out.extend_from_slice(c1);
+    match format {
+        C1C3C2 | C1C3C2Raw => {
+            out.extend_from_slice(c3);
+            out.extend_from_slice(c2);
+        }
+        _ => {
+            out.extend_from_slice(c2);
+            out.extend_from_slice(c3);
+        }
+    }
+    out
 }
 
-/// 计算Z值
-fn calculate_z(data: &[u8]) -> Vec<u8> {
-    // Z值计算：Z = SM3(ENTL || ID || a || b || x_G || y_G || x_A || y_A)
-    // 注意：这里使用简化实现，实际应用中需要根据标准计算完整的Z值
+/// 计算 C3 = SM3(x2 || M || y2)
+fn ciphertext_hash(shared: &[u8; 64], message: &[u8]) -> [u8; 32] {
     let mut hasher = Sm3::new();
-    hasher.update(data);
-    let digest = hasher.finalize();
-    digest.to_vec()
-}
-
-/// SM2 KDF密钥派生函数
-fn sm2_kdf(z: &[u8], klen: usize) -> Vec<u8> {
-    // KDF(Z, klen) = ||_{i=1}^ceil(klen/32) SM3(Z || i)
-    let mut result = Vec::new();
-    let mut counter: u32 = 1;
-    
-    while result.len() < klen {
-        // 构造输入：Z || i
-        let mut input = Vec::new();
-        input.extend_from_slice(z);
-        input.extend_from_slice(&counter.to_be_bytes());
-        
-        // 计算SM3哈希
-        let mut hasher = Sm3::new();
-        hasher.update(&input);
-        let hash = hasher.finalize();
-        
-        // 添加到结果中
-        result.extend_from_slice(&hash);
-        counter += 1;
+    hasher.update(&shared[..32]);
+    hasher.update(message);
+    hasher.update(&shared[32..]);
+    hasher.finalize().into()
+}
+
+/// 计算Z值：Z_A = SM3(ENTL || ID || a || b || x_G || y_G || x_A || y_A)
+///
+/// 默认ID为 [`SM2_DEFAULT_ID`]；ID超过 [`SM2_MAX_ID_LEN`] 字节时返回None
+pub fn sm2_compute_z(public_key: &[u8; 64], id: &[u8]) -> Option<[u8; 32]> {
+    if id.len() > SM2_MAX_ID_LEN {
+        return None;
     }
     
-    // 截取需要的长度
-    result.truncate(klen);
-    result
-}
\ No newline at end of file
+    // ENTL为ID的比特长度，占两个字节
+    let entl = ((id.len() * 8) as u16).to_be_bytes();
+    
+    let mut hasher = Sm3::new();
+    hasher.update(entl);
+    hasher.update(id);
+    hasher.update(SM2_A);
+    hasher.update(SM2_B);
+    hasher.update(SM2_GX);
+    hasher.update(SM2_GY);
+    hasher.update(public_key);
+    Some(hasher.finalize().into())
+}
@@ -0,0 +1,69 @@
+// 确定性签名随机数（参照 RFC 6979 第3.2节，HMAC-DRBG 基于 HMAC-SM3）
+//
+// qlen = hlen = 256，因此 bits2int 即按大端解释，bits2octets(e) = e mod n
+
+use super::curve::Scalar;
+use crate::sm3::hmac_sm3;
+use zeroize::Zeroize;
+
+/// 由私钥d与摘要e派生签名随机数k的HMAC-DRBG
+pub(super) struct NonceGenerator {
+    k: [u8; 32],
+    v: [u8; 32],
+}
+
+impl NonceGenerator {
+    /// 以 d、e 以及可选的附加随机数据初始化
+    pub(super) fn new(d: &Scalar, e: &[u8; 32], extra: &[u8]) -> Self {
+        let mut generator = Self {
+            k: [0x00; 32],
+            v: [0x01; 32],
+        };
+        let mut private_key = d.to_bytes();
+        let digest = Scalar::from_bytes_reduced(e).to_bytes();
+
+        // K = HMAC_K(V || 0x00 || int2octets(d) || bits2octets(e) || extra)，V = HMAC_K(V)
+        // K = HMAC_K(V || 0x01 || int2octets(d) || bits2octets(e) || extra)，V = HMAC_K(V)
+        for separator in [0x00, 0x01] {
+            let mut data = Vec::with_capacity(97 + extra.len());
+            data.extend_from_slice(&generator.v);
+            data.push(separator);
+            data.extend_from_slice(&private_key);
+            data.extend_from_slice(&digest);
+            data.extend_from_slice(extra);
+            generator.k = hmac_sm3(&generator.k, &data);
+            generator.v = hmac_sm3(&generator.k, &generator.v);
+            data.zeroize();
+        }
+        private_key.zeroize();
+        generator
+    }
+
+    /// 输出下一个 [1, n-1] 中的k；签名方拒绝当前k时再次调用即可
+    pub(super) fn next_scalar(&mut self) -> Scalar {
+        loop {
+            self.v = hmac_sm3(&self.k, &self.v);
+            let candidate = Scalar::from_bytes(&self.v);
+
+            // 无论候选值是否可用，都先按 K = HMAC_K(V || 0x00)，V = HMAC_K(V) 推进状态
+            let mut data = [0u8; 33];
+            data[..32].copy_from_slice(&self.v);
+            self.k = hmac_sm3(&self.k, &data);
+            self.v = hmac_sm3(&self.k, &self.v);
+
+            if let Some(k) = candidate
+                && !bool::from(k.is_zero())
+            {
+                return k;
+            }
+        }
+    }
+}
+
+/// DRBG状态由私钥派生，释放时清零
+impl Drop for NonceGenerator {
+    fn drop(&mut self) {
+        self.k.zeroize();
+        self.v.zeroize();
+    }
+}
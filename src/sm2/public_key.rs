@@ -0,0 +1,78 @@
+// SM2公钥及其SEC1点编码（未压缩04、压缩02/03、混合06/07）
+
+use super::curve::{AffinePoint, ProjectivePoint};
+use super::{private_key_scalar, public_key_point};
+use subtle::Choice;
+
+/// 公钥点的SEC1编码方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sm2PointFormat {
+    /// 04 || x || y，65字节
+    Uncompressed,
+    /// 02/03 || x，33字节，前缀表示y的奇偶
+    Compressed,
+    /// 06/07 || x || y，65字节，前缀同时表示y的奇偶
+    Hybrid,
+}
+
+/// SM2公钥，始终是曲线上的点
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Sm2PublicKey {
+    point: AffinePoint,
+}
+
+impl Sm2PublicKey {
+    /// 从 x || y 编码解析，未通过公钥校验时返回None
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<Self> {
+        public_key_point(bytes).map(|point| Self { point })
+    }
+
+    /// 输出 x || y 编码，与 `sm2_generate_keypair` 返回的公钥格式相同
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.point.to_bytes()
+    }
+
+    /// 由私钥计算公钥，私钥不在 [1, n-2] 范围内时返回None
+    pub fn from_private_key(private_key: &[u8; 32]) -> Option<Self> {
+        let d = private_key_scalar(private_key)?;
+        let point = ProjectivePoint::mul_base(&d).to_affine()?;
+        Some(Self { point })
+    }
+
+    /// 解析SEC1编码，自动识别前缀
+    ///
+    /// 长度与前缀不符、坐标不小于p、点不在曲线上、[n]Q ≠ O 或混合编码的奇偶与y不一致时返回None
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&tag, body) = bytes.split_first()?;
+        let point = match (tag, body.len()) {
+            (0x02 | 0x03, 32) => AffinePoint::decompress(body.try_into().unwrap(), Choice::from(tag & 1))?,
+            (0x04, 64) => AffinePoint::from_bytes(body.try_into().unwrap())?,
+            (0x06 | 0x07, 64) => {
+                let point = AffinePoint::from_bytes(body.try_into().unwrap())?;
+                if point.y.is_odd().unwrap_u8() != tag & 1 {
+                    return None;
+                }
+                point
+            }
+            _ => return None,
+        };
+        if !point.has_order_n() {
+            return None;
+        }
+        Some(Self { point })
+    }
+
+    /// 按指定格式输出SEC1编码
+    pub fn to_sec1_bytes(&self, format: Sm2PointFormat) -> Vec<u8> {
+        let parity = self.point.y.is_odd().unwrap_u8();
+        let (tag, len) = match format {
+            Sm2PointFormat::Uncompressed => (0x04, 65),
+            Sm2PointFormat::Compressed => (0x02 | parity, 33),
+            Sm2PointFormat::Hybrid => (0x06 | parity, 65),
+        };
+        let mut out = Vec::with_capacity(len);
+        out.push(tag);
+        out.extend_from_slice(&self.point.to_bytes()[..len - 1]);
+        out
+    }
+}
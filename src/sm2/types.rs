@@ -0,0 +1,248 @@
+// SM2强类型私钥、签名与密文，构造时即完成校验
+//
+// 各方法只是对同名自由函数的薄封装；私钥在释放时清零，Debug输出不包含私钥内容
+
+use super::curve::AffinePoint;
+use super::{
+    SM2_DEFAULT_ID, Sm2CiphertextFormat, Sm2PublicKey, private_key_scalar, signature_scalars, sm2_convert_ciphertext,
+    sm2_decrypt, sm2_encrypt, sm2_generate_keypair, sm2_sign_digest, sm2_sign_with_id, sm2_signature_from_der,
+    sm2_signature_to_der, sm2_verify_digest, sm2_verify_with_id,
+};
+use std::fmt;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// SM2私钥，取值在 [1, n-2] 中，释放时清零
+#[derive(Clone)]
+pub struct Sm2PrivateKey {
+    bytes: [u8; 32],
+}
+
+/// SM2签名 r || s，r、s 均在 [1, n-1] 中
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Sm2Signature {
+    bytes: [u8; 64],
+}
+
+/// SM2密文，内部按 GB/T 32918.4 的 04 || C1 || C3 || C2 格式保存
+#[derive(Clone, PartialEq, Eq)]
+pub struct Sm2Ciphertext {
+    bytes: Vec<u8>,
+}
+
+impl Sm2PrivateKey {
+    /// 随机生成私钥
+    pub fn generate() -> Self {
+        let (mut bytes, _) = sm2_generate_keypair();
+        let key = Self { bytes };
+        bytes.zeroize();
+        key
+    }
+
+    /// 从32字节大端编码解析，不在 [1, n-2] 范围内时返回None
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        private_key_scalar(bytes)?;
+        Some(Self { bytes: *bytes })
+    }
+
+    /// 从64位十六进制串解析
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let mut bytes = decode_hex(hex)?;
+        let key = <&[u8; 32]>::try_from(bytes.as_slice()).ok().and_then(Self::from_bytes);
+        bytes.zeroize();
+        key
+    }
+
+    /// 输出32字节大端编码，调用方负责清零副本
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.bytes
+    }
+
+    pub fn to_hex(&self) -> String {
+        encode_hex(&self.bytes)
+    }
+
+    /// 对应的公钥
+    pub fn public_key(&self) -> Sm2PublicKey {
+        Sm2PublicKey::from_private_key(&self.bytes).unwrap()
+    }
+
+    /// 使用默认用户身份标识签名
+    pub fn sign(&self, message: &[u8]) -> Sm2Signature {
+        self.sign_with_id(SM2_DEFAULT_ID, message).unwrap()
+    }
+
+    /// 使用指定用户身份标识签名，ID超长时返回None
+    pub fn sign_with_id(&self, id: &[u8], message: &[u8]) -> Option<Sm2Signature> {
+        Sm2Signature::from_bytes(&sm2_sign_with_id(&self.bytes, id, message))
+    }
+
+    /// 对预先计算好的摘要e签名
+    pub fn sign_digest(&self, digest: &[u8; 32]) -> Sm2Signature {
+        Sm2Signature::from_bytes(&sm2_sign_digest(&self.bytes, digest)).unwrap()
+    }
+
+    /// 解密，C3校验失败时返回None
+    pub fn decrypt(&self, ciphertext: &Sm2Ciphertext) -> Option<Vec<u8>> {
+        sm2_decrypt(&self.bytes, &ciphertext.bytes)
+    }
+}
+
+impl Drop for Sm2PrivateKey {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl PartialEq for Sm2PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.bytes.ct_eq(&other.bytes))
+    }
+}
+
+impl Eq for Sm2PrivateKey {}
+
+impl fmt::Debug for Sm2PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sm2PrivateKey([REDACTED])")
+    }
+}
+
+impl Sm2PublicKey {
+    /// 从128位十六进制的 x || y 解析
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        Self::from_bytes(&decode_hex(hex)?.try_into().ok()?)
+    }
+
+    pub fn to_hex(&self) -> String {
+        encode_hex(&self.to_bytes())
+    }
+
+    /// 使用默认用户身份标识验签
+    pub fn verify(&self, message: &[u8], signature: &Sm2Signature) -> bool {
+        self.verify_with_id(SM2_DEFAULT_ID, message, signature)
+    }
+
+    /// 使用指定用户身份标识验签
+    pub fn verify_with_id(&self, id: &[u8], message: &[u8], signature: &Sm2Signature) -> bool {
+        sm2_verify_with_id(&self.to_bytes(), id, message, &signature.bytes)
+    }
+
+    /// 验证摘要e上的签名
+    pub fn verify_digest(&self, digest: &[u8; 32], signature: &Sm2Signature) -> bool {
+        sm2_verify_digest(&self.to_bytes(), digest, &signature.bytes)
+    }
+
+    /// 加密，消息为空时返回None
+    pub fn encrypt(&self, message: &[u8]) -> Option<Sm2Ciphertext> {
+        let bytes = sm2_encrypt(&self.to_bytes(), message);
+        if bytes.is_empty() { None } else { Some(Sm2Ciphertext { bytes }) }
+    }
+}
+
+impl fmt::Debug for Sm2PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sm2PublicKey({})", self.to_hex())
+    }
+}
+
+impl Sm2Signature {
+    /// 从 r || s 解析，r、s 不在 [1, n-1] 范围内时返回None
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<Self> {
+        signature_scalars(bytes)?;
+        Some(Self { bytes: *bytes })
+    }
+
+    /// 严格解析GM/T 0009的DER编码 SEQUENCE { r INTEGER, s INTEGER }
+    pub fn from_der(der: &[u8]) -> Option<Self> {
+        Some(Self { bytes: sm2_signature_from_der(der)? })
+    }
+
+    /// 从128位十六进制的 r || s 解析
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        Self::from_bytes(&decode_hex(hex)?.try_into().ok()?)
+    }
+
+    /// 输出 r || s
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.bytes
+    }
+
+    /// 输出DER编码
+    pub fn to_der(&self) -> Vec<u8> {
+        sm2_signature_to_der(&self.bytes).unwrap()
+    }
+
+    pub fn to_hex(&self) -> String {
+        encode_hex(&self.bytes)
+    }
+
+    pub fn r(&self) -> [u8; 32] {
+        self.bytes[..32].try_into().unwrap()
+    }
+
+    pub fn s(&self) -> [u8; 32] {
+        self.bytes[32..].try_into().unwrap()
+    }
+}
+
+impl fmt::Debug for Sm2Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sm2Signature({})", self.to_hex())
+    }
+}
+
+impl Sm2Ciphertext {
+    /// 从 04 || C1 || C3 || C2 解析，长度不足、前缀错误或C1不在曲线上时返回None
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_format(bytes, Sm2CiphertextFormat::C1C3C2)
+    }
+
+    /// 按指定格式解析
+    pub fn from_format(bytes: &[u8], format: Sm2CiphertextFormat) -> Option<Self> {
+        let bytes = sm2_convert_ciphertext(bytes, format, Sm2CiphertextFormat::C1C3C2)?;
+        AffinePoint::from_bytes(bytes[1..65].try_into().unwrap())?;
+        Some(Self { bytes })
+    }
+
+    /// 从十六进制串解析 04 || C1 || C3 || C2
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        Self::from_bytes(&decode_hex(hex)?)
+    }
+
+    /// 04 || C1 || C3 || C2
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// 转换为指定格式
+    pub fn to_format(&self, format: Sm2CiphertextFormat) -> Vec<u8> {
+        sm2_convert_ciphertext(&self.bytes, Sm2CiphertextFormat::C1C3C2, format).unwrap()
+    }
+
+    pub fn to_hex(&self) -> String {
+        encode_hex(&self.bytes)
+    }
+}
+
+impl fmt::Debug for Sm2Ciphertext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sm2Ciphertext({})", self.to_hex())
+    }
+}
+
+/// 编码为大写十六进制
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+/// 解析十六进制（大小写均可），长度为奇数或含非法字符时返回None
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
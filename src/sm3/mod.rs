@@ -15,9 +15,7 @@ pub fn sm3_hash(data: &[u8]) -> [u8; 32] {
         } else {
             buffer[..remain].copy_from_slice(&data[pos..]);
             buffer[remain] = 0x80;
-            for i in remain+1..64 {
-                buffer[i] = 0;
-            }
+            buffer[remain+1..].fill(0);
             if remain <= 55 {
                 for i in 0..8 {
                     buffer[56+i] = ((len >> (56 - i*8)) & 0xFF) as u8;
@@ -25,9 +23,7 @@ pub fn sm3_hash(data: &[u8]) -> [u8; 32] {
                 compress(&mut state, &buffer);
             } else {
                 compress(&mut state, &buffer);
-                for i in 0..64 {
-                    buffer[i] = 0;
-                }
+                buffer.fill(0);
                 for i in 0..8 {
                     buffer[56+i] = ((len >> (56 - i*8)) & 0xFF) as u8;
                 }
@@ -38,11 +34,9 @@ pub fn sm3_hash(data: &[u8]) -> [u8; 32] {
     }
     
     // 处理正好64字节的情况
-    if data_len % 64 == 0 && data_len > 0 {
+    if data_len.is_multiple_of(64) && data_len > 0 {
         buffer[0] = 0x80;
-        for i in 1..64 {
-            buffer[i] = 0;
-        }
+        buffer[1..].fill(0);
         for i in 0..8 {
             buffer[56+i] = ((len >> (56 - i*8)) & 0xFF) as u8;
         }
@@ -51,9 +45,7 @@ pub fn sm3_hash(data: &[u8]) -> [u8; 32] {
     
     if data.is_empty() {
         buffer[0] = 0x80;
-        for i in 1..64 {
-            buffer[i] = 0;
-        }
+        buffer[1..].fill(0);
         for i in 0..8 {
             buffer[56+i] = ((len >> (56 - i*8)) & 0xFF) as u8;
         }
@@ -99,6 +91,26 @@ pub fn hmac_sm3(key: &[u8], data: &[u8]) -> [u8; 32] {
     sm3_hash(&outer)
 }
 
+/// PBKDF2（RFC 8018），伪随机函数为HMAC-SM3，输出长度由out决定
+pub fn pbkdf2_hmac_sm3(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    let mut salt_block = Vec::with_capacity(salt.len() + 4);
+    for (i, chunk) in out.chunks_mut(32).enumerate() {
+        // U_1 = PRF(P, S || INT(i))，T_i = U_1 ^ U_2 ^ ... ^ U_c
+        salt_block.clear();
+        salt_block.extend_from_slice(salt);
+        salt_block.extend_from_slice(&(i as u32 + 1).to_be_bytes());
+        let mut u = hmac_sm3(password, &salt_block);
+        let mut t = u;
+        for _ in 1..iterations {
+            u = hmac_sm3(password, &u);
+            for (a, b) in t.iter_mut().zip(u.iter()) {
+                *a ^= b;
+            }
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
+
 fn compress(state: &mut [u32; 8], data: &[u8]) {
     let mut w = [0u32; 68];
     let mut w1 = [0u32; 64];
@@ -15,7 +15,7 @@ fn u32big_to_u8big(out: &mut [u8; 16], input: &[u32; 4]) {
 }
 
 fn sm4_rotl(x: u32, n: u8) -> u32 {
-    (x << n) | (x >> (32 - n))
+    x.rotate_left(n as u32)
 }
 
 fn sm4_tao(a: u32) -> u32 {
@@ -178,9 +178,7 @@ pub fn sm4_encrypt_cbc(key: &[u8; 16], iv: &[u8; 16], plaintext: &[u8], cipherte
             plaintext_block_bytes[..plaintext_end - plaintext_start].copy_from_slice(&plaintext[plaintext_start..plaintext_end]);
         }
         // 填充剩余字节为0
-        for j in plaintext_end - plaintext_start..16 {
-            plaintext_block_bytes[j] = 0;
-        }
+        plaintext_block_bytes[plaintext_end - plaintext_start..].fill(0);
         
         let mut t1 = [0u32; 4];
         u8big_to_u32big(&mut t1, &plaintext_block_bytes);
@@ -223,8 +221,8 @@ pub fn sm4_decrypt_cbc(key: &[u8; 16], iv: &[u8; 16], ciphertext: &[u8], plainte
         
         for j in 0..4 {
             t2[j] ^= init_vec[j];
-            init_vec[j] = t1[j];
         }
+        init_vec.copy_from_slice(&t1);
         
         let mut plaintext_block_bytes = [0u8; 16];
         u32big_to_u8big(&mut plaintext_block_bytes, &t2);
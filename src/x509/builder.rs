@@ -0,0 +1,141 @@
+// X.509 v3 证书构造与签发
+
+use super::certificate::X509Certificate;
+use super::csr::X509Csr;
+use super::extension::{
+    OID_AUTHORITY_KEY_IDENTIFIER, OID_EXTENDED_KEY_USAGE, OID_SUBJECT_ALT_NAME, OID_SUBJECT_KEY_IDENTIFIER,
+    X509Extension,
+};
+use super::name::X509Name;
+use super::{key_identifier, sign_structure, signature_algorithm};
+use crate::asn1::{TAG_CONTEXT, encode_sequence, encode_time, encode_tlv, encode_unsigned_integer};
+use crate::sm2::{Sm2PrivateKey, Sm2PublicKey};
+use rand::RngCore;
+use rand::rngs::OsRng;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 默认有效期一年
+const DEFAULT_VALIDITY: i64 = 365 * 86400;
+
+/// 证书构造器
+///
+/// 未设置时序列号为16字节随机正整数，有效期为当前时刻起一年；
+/// 未显式添加时自动生成SKI与AKI，密钥标识取 SM3(subjectPublicKey) 的前20字节
+#[derive(Clone, Debug)]
+pub struct X509CertificateBuilder {
+    serial_number: Vec<u8>,
+    subject: X509Name,
+    public_key: Sm2PublicKey,
+    not_before: i64,
+    not_after: i64,
+    extensions: Vec<X509Extension>,
+}
+
+impl X509CertificateBuilder {
+    pub fn new(subject: X509Name, public_key: Sm2PublicKey) -> Self {
+        let mut serial_number = vec![0u8; 16];
+        OsRng.fill_bytes(&mut serial_number);
+        serial_number[0] = (serial_number[0] & 0x7F) | 0x40;
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+        Self {
+            serial_number,
+            subject,
+            public_key,
+            not_before: now,
+            not_after: now + DEFAULT_VALIDITY,
+            extensions: Vec::new(),
+        }
+    }
+
+    /// 以证书请求中的主体与公钥为起点，请求自签名无效时返回None
+    ///
+    /// 请求来自申请者，不可信：只沿用其中的subjectAltName与extendedKeyUsage，
+    /// basicConstraints、keyUsage等其余扩展须由签发者自行添加
+    pub fn from_csr(csr: &X509Csr) -> Option<Self> {
+        if !csr.verify() {
+            return None;
+        }
+        let mut builder = Self::new(csr.subject().clone(), csr.public_key());
+        builder.extensions = csr
+            .extensions()
+            .iter()
+            .filter(|e| e.oid == OID_SUBJECT_ALT_NAME || e.oid == OID_EXTENDED_KEY_USAGE)
+            .cloned()
+            .collect();
+        Some(builder)
+    }
+
+    /// 设置序列号（大端无符号整数），须非零且INTEGER编码不超过20字节（RFC 5280 4.1.2.2），否则签发时返回None
+    pub fn serial_number(mut self, serial_number: &[u8]) -> Self {
+        self.serial_number = serial_number.to_vec();
+        self
+    }
+
+    /// 设置有效期，参数为Unix时间戳（秒），notBefore晚于notAfter时签发返回None
+    pub fn validity(mut self, not_before: i64, not_after: i64) -> Self {
+        self.not_before = not_before;
+        self.not_after = not_after;
+        self
+    }
+
+    /// 添加扩展，同一OID的扩展会被替换
+    pub fn extension(mut self, extension: X509Extension) -> Self {
+        self.extensions.retain(|e| e.oid != extension.oid);
+        self.extensions.push(extension);
+        self
+    }
+
+    /// 用与主体公钥对应的私钥自签，私钥不匹配时返回None
+    pub fn self_signed(&self, key: &Sm2PrivateKey) -> Option<X509Certificate> {
+        if key.public_key() != self.public_key {
+            return None;
+        }
+        self.build(&self.subject, &key_identifier(&self.public_key), key)
+    }
+
+    /// 由签发者证书及其私钥签发，私钥与签发者证书不匹配时返回None
+    pub fn sign(&self, issuer: &X509Certificate, issuer_key: &Sm2PrivateKey) -> Option<X509Certificate> {
+        if issuer_key.public_key() != issuer.public_key() {
+            return None;
+        }
+        let authority_key_id = issuer.subject_key_identifier().unwrap_or_else(|| key_identifier(&issuer.public_key()));
+        self.build(issuer.subject(), &authority_key_id, issuer_key)
+    }
+
+    fn build(&self, issuer: &X509Name, authority_key_id: &[u8], key: &Sm2PrivateKey) -> Option<X509Certificate> {
+        if !is_valid_serial_number(&self.serial_number) || self.not_before > self.not_after {
+            return None;
+        }
+        let mut extensions = self.extensions.clone();
+        if !extensions.iter().any(|e| e.oid == OID_SUBJECT_KEY_IDENTIFIER) {
+            extensions.push(X509Extension::subject_key_identifier(&key_identifier(&self.public_key)));
+        }
+        if !extensions.iter().any(|e| e.oid == OID_AUTHORITY_KEY_IDENTIFIER) {
+            extensions.push(X509Extension::authority_key_identifier(authority_key_id));
+        }
+        let extensions = extensions.iter().map(X509Extension::to_der).collect::<Option<Vec<_>>>()?;
+        let extensions = encode_sequence(&extensions.iter().map(Vec::as_slice).collect::<Vec<_>>());
+
+        let tbs = encode_sequence(&[
+            // version [0] EXPLICIT v3
+            &encode_tlv(TAG_CONTEXT, &encode_unsigned_integer(&[2])),
+            &encode_unsigned_integer(&self.serial_number),
+            &signature_algorithm(),
+            issuer.as_der(),
+            &encode_sequence(&[&encode_time(self.not_before), &encode_time(self.not_after)]),
+            self.subject.as_der(),
+            &self.public_key.to_public_key_der(),
+            &encode_tlv(TAG_CONTEXT | 3, &extensions),
+        ]);
+        X509Certificate::from_der(&sign_structure(&tbs, key))
+    }
+}
+
+/// 序列号为正整数，编码后的INTEGER内容（含最高位为1时补的0x00）不超过20字节
+fn is_valid_serial_number(serial_number: &[u8]) -> bool {
+    let skip = serial_number.iter().take_while(|&&b| b == 0).count();
+    let trimmed = &serial_number[skip..];
+    let len = trimmed.len() + trimmed.first().map_or(0, |&b| (b >> 7) as usize);
+    (1..=20).contains(&len)
+}
@@ -0,0 +1,249 @@
+// X.509 v3 证书解析与SM2签名验证
+
+use super::extension::{
+    OID_AUTHORITY_KEY_IDENTIFIER, OID_BASIC_CONSTRAINTS, OID_EXTENDED_KEY_USAGE, OID_KEY_USAGE, OID_SUBJECT_ALT_NAME,
+    OID_SUBJECT_KEY_IDENTIFIER, X509BasicConstraints, X509Extension, X509GeneralName, X509KeyUsage,
+    parse_authority_key_identifier, parse_basic_constraints, parse_extended_key_usage, parse_extensions,
+    parse_general_names, parse_key_usage, parse_subject_key_identifier,
+};
+use super::name::X509Name;
+use super::check_signature_algorithm;
+use crate::asn1::{
+    DerReader, TAG_CONTEXT, TAG_CONTEXT_PRIMITIVE, TAG_INTEGER, TAG_SEQUENCE, pem_decode, pem_decode_all, pem_encode,
+};
+use crate::sm2::{SM2_DEFAULT_ID, Sm2PublicKey, Sm2Signature};
+
+const PEM_CERTIFICATE: &str = "CERTIFICATE";
+
+/// 使用SM2公钥、以SM2-with-SM3签名的X.509证书
+#[derive(Clone, PartialEq, Eq)]
+pub struct X509Certificate {
+    der: Vec<u8>,
+    tbs: Vec<u8>,
+    version: u8,
+    serial_number: Vec<u8>,
+    issuer: X509Name,
+    subject: X509Name,
+    not_before: i64,
+    not_after: i64,
+    public_key: Sm2PublicKey,
+    extensions: Vec<X509Extension>,
+    signature: Vec<u8>,
+}
+
+impl X509Certificate {
+    /// 解析DER编码的证书
+    ///
+    /// 签名算法不是SM2-with-SM3、公钥不是SM2公钥、已知扩展格式错误或有重复扩展时返回None
+    pub fn from_der(der: &[u8]) -> Option<Self> {
+        let mut outer = DerReader::new(der);
+        let mut certificate = outer.read_sequence()?;
+        outer.finish()?;
+
+        let tbs = certificate.read_raw(TAG_SEQUENCE)?;
+        let signature_algorithm = certificate.read_raw(TAG_SEQUENCE)?;
+        let signature = certificate.read_bit_string()?;
+        certificate.finish()?;
+        check_signature_algorithm(signature_algorithm)?;
+
+        let mut outer = DerReader::new(tbs);
+        let mut fields = outer.read_sequence()?;
+
+        // version [0] EXPLICIT INTEGER DEFAULT v1
+        let version = match fields.read_optional(TAG_CONTEXT) {
+            Some(value) => {
+                let mut reader = DerReader::new(value);
+                let version = reader.read_unsigned_integer()?;
+                reader.finish()?;
+                match version {
+                    [v @ 0..=2] => v + 1,
+                    _ => return None,
+                }
+            }
+            None => 1,
+        };
+        let serial_number = fields.read(TAG_INTEGER)?;
+        if serial_number.is_empty() {
+            return None;
+        }
+        // tbsCertificate.signature 须与外层签名算法一致
+        if fields.read_raw(TAG_SEQUENCE)? != signature_algorithm {
+            return None;
+        }
+        let issuer = X509Name::from_der(fields.read_raw(TAG_SEQUENCE)?)?;
+        let mut validity = fields.read_sequence()?;
+        let not_before = validity.read_time()?;
+        let not_after = validity.read_time()?;
+        validity.finish()?;
+        let subject = X509Name::from_der(fields.read_raw(TAG_SEQUENCE)?)?;
+        let public_key = Sm2PublicKey::from_public_key_der(fields.read_raw(TAG_SEQUENCE)?)?;
+
+        // issuerUniqueID [1]、subjectUniqueID [2] 仅v2/v3允许，直接忽略
+        if version >= 2 {
+            fields.read_optional(TAG_CONTEXT_PRIMITIVE | 1);
+            fields.read_optional(TAG_CONTEXT_PRIMITIVE | 2);
+        }
+        let extensions = match fields.read_optional(TAG_CONTEXT | 3) {
+            Some(value) if version == 3 => parse_extensions(value)?,
+            Some(_) => return None,
+            None => Vec::new(),
+        };
+        fields.finish()?;
+
+        Some(Self {
+            der: der.to_vec(),
+            tbs: tbs.to_vec(),
+            version,
+            serial_number: serial_number.to_vec(),
+            issuer,
+            subject,
+            not_before,
+            not_after,
+            public_key,
+            extensions,
+            signature: signature.to_vec(),
+        })
+    }
+
+    /// 解析 `-----BEGIN CERTIFICATE-----` PEM，只读取第一个证书
+    pub fn from_pem(pem: &str) -> Option<Self> {
+        let (label, der) = pem_decode(pem)?;
+        if label != PEM_CERTIFICATE {
+            return None;
+        }
+        Self::from_der(&der)
+    }
+
+    /// 按顺序解析文本中全部 `CERTIFICATE` 块，其他标签的块被忽略，任一证书非法时返回None
+    pub fn from_pem_all(pem: &str) -> Option<Vec<Self>> {
+        pem_decode_all(pem)?
+            .into_iter()
+            .filter(|(label, _)| label == PEM_CERTIFICATE)
+            .map(|(_, der)| Self::from_der(&der))
+            .collect()
+    }
+
+    pub fn as_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    pub fn to_pem(&self) -> String {
+        pem_encode(PEM_CERTIFICATE, &self.der)
+    }
+
+    /// 被签名的tbsCertificate编码
+    pub fn tbs_der(&self) -> &[u8] {
+        &self.tbs
+    }
+
+    /// 证书版本，1、2或3
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// 序列号INTEGER的内容字节（大端，含可能的前导0x00）
+    pub fn serial_number(&self) -> &[u8] {
+        &self.serial_number
+    }
+
+    pub fn issuer(&self) -> &X509Name {
+        &self.issuer
+    }
+
+    pub fn subject(&self) -> &X509Name {
+        &self.subject
+    }
+
+    /// 有效期起点，Unix时间戳（秒）
+    pub fn not_before(&self) -> i64 {
+        self.not_before
+    }
+
+    /// 有效期终点，Unix时间戳（秒）
+    pub fn not_after(&self) -> i64 {
+        self.not_after
+    }
+
+    /// 给定时刻是否在有效期内（含端点）
+    pub fn is_valid_at(&self, unix: i64) -> bool {
+        self.not_before <= unix && unix <= self.not_after
+    }
+
+    pub fn public_key(&self) -> Sm2PublicKey {
+        self.public_key
+    }
+
+    /// DER编码的SM2签名值
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    pub fn extensions(&self) -> &[X509Extension] {
+        &self.extensions
+    }
+
+    /// 按点分OID查找扩展
+    pub fn extension(&self, oid: &str) -> Option<&X509Extension> {
+        self.extensions.iter().find(|e| e.oid == oid)
+    }
+
+    pub fn basic_constraints(&self) -> Option<X509BasicConstraints> {
+        parse_basic_constraints(&self.extension(OID_BASIC_CONSTRAINTS)?.value)
+    }
+
+    pub fn key_usage(&self) -> Option<X509KeyUsage> {
+        parse_key_usage(&self.extension(OID_KEY_USAGE)?.value)
+    }
+
+    /// extendedKeyUsage 中的点分OID
+    pub fn extended_key_usage(&self) -> Option<Vec<String>> {
+        parse_extended_key_usage(&self.extension(OID_EXTENDED_KEY_USAGE)?.value)
+    }
+
+    pub fn subject_alt_names(&self) -> Option<Vec<X509GeneralName>> {
+        parse_general_names(&self.extension(OID_SUBJECT_ALT_NAME)?.value)
+    }
+
+    pub fn subject_key_identifier(&self) -> Option<Vec<u8>> {
+        parse_subject_key_identifier(&self.extension(OID_SUBJECT_KEY_IDENTIFIER)?.value)
+    }
+
+    /// authorityKeyIdentifier 中的 keyIdentifier
+    pub fn authority_key_identifier(&self) -> Option<Vec<u8>> {
+        parse_authority_key_identifier(&self.extension(OID_AUTHORITY_KEY_IDENTIFIER)?.value)?
+    }
+
+    /// 用签发者公钥验证证书签名，按GM/T 0015使用默认用户身份标识
+    pub fn verify_signature(&self, issuer_key: &Sm2PublicKey) -> bool {
+        self.verify_signature_with_id(issuer_key, SM2_DEFAULT_ID)
+    }
+
+    /// 用指定的签名者身份标识验证证书签名
+    ///
+    /// OpenSSL 3 未指定 `-sigopt distid:` 时以空标识签发证书，可传入空切片验证
+    pub fn verify_signature_with_id(&self, issuer_key: &Sm2PublicKey, id: &[u8]) -> bool {
+        Sm2Signature::from_der(&self.signature)
+            .is_some_and(|signature| issuer_key.verify_with_id(id, &self.tbs, &signature))
+    }
+
+    /// 检查本证书由issuer签发：签发者名称一致且签名有效
+    pub fn verify_issued_by(&self, issuer: &X509Certificate) -> bool {
+        self.issuer == issuer.subject && self.verify_signature(&issuer.public_key)
+    }
+
+    /// 签发者与主体相同且能用自身公钥验证
+    pub fn is_self_signed(&self) -> bool {
+        self.verify_issued_by(self)
+    }
+}
+
+impl std::fmt::Debug for X509Certificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("X509Certificate")
+            .field("subject", &self.subject)
+            .field("issuer", &self.issuer)
+            .field("not_before", &self.not_before)
+            .field("not_after", &self.not_after)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,321 @@
+// 证书吊销列表（RFC 5280第5节），以SM2-with-SM3签名
+
+use super::certificate::X509Certificate;
+use super::check_signature_algorithm;
+use super::extension::{OID_AUTHORITY_KEY_IDENTIFIER, X509Extension, parse_authority_key_identifier, parse_extensions};
+use super::name::X509Name;
+use crate::asn1::{
+    DerReader, TAG_CONTEXT, TAG_ENUMERATED, TAG_GENERALIZED_TIME, TAG_INTEGER, TAG_SEQUENCE, TAG_UTC_TIME, pem_decode,
+    pem_encode,
+};
+use crate::sm2::{SM2_DEFAULT_ID, Sm2PublicKey, Sm2Signature};
+use std::fmt;
+
+const PEM_CRL: &str = "X509 CRL";
+
+const OID_CRL_NUMBER: &str = "2.5.29.20";
+const OID_CRL_REASON: &str = "2.5.29.21";
+const OID_INVALIDITY_DATE: &str = "2.5.29.24";
+
+/// 以SM2-with-SM3签名的X.509 v1/v2 CRL
+#[derive(Clone, PartialEq, Eq)]
+pub struct X509Crl {
+    der: Vec<u8>,
+    tbs: Vec<u8>,
+    version: u8,
+    issuer: X509Name,
+    this_update: i64,
+    next_update: Option<i64>,
+    revoked: Vec<X509RevokedCertificate>,
+    extensions: Vec<X509Extension>,
+    signature: Vec<u8>,
+}
+
+/// CRL中的一条吊销记录
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X509RevokedCertificate {
+    /// 序列号INTEGER的内容字节
+    pub serial_number: Vec<u8>,
+    /// 吊销时间，Unix时间戳（秒）
+    pub revocation_date: i64,
+    /// reasonCode 条目扩展，缺省时为None
+    pub reason: Option<X509CrlReason>,
+    pub extensions: Vec<X509Extension>,
+}
+
+/// 吊销原因（CRLReason），取值与RFC 5280一致
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum X509CrlReason {
+    Unspecified = 0,
+    KeyCompromise = 1,
+    CaCompromise = 2,
+    AffiliationChanged = 3,
+    Superseded = 4,
+    CessationOfOperation = 5,
+    CertificateHold = 6,
+    RemoveFromCrl = 8,
+    PrivilegeWithdrawn = 9,
+    AaCompromise = 10,
+}
+
+impl X509CrlReason {
+    /// 由枚举值构造，7及超出范围的值返回None
+    pub fn from_code(code: u8) -> Option<Self> {
+        Some(match code {
+            0 => Self::Unspecified,
+            1 => Self::KeyCompromise,
+            2 => Self::CaCompromise,
+            3 => Self::AffiliationChanged,
+            4 => Self::Superseded,
+            5 => Self::CessationOfOperation,
+            6 => Self::CertificateHold,
+            8 => Self::RemoveFromCrl,
+            9 => Self::PrivilegeWithdrawn,
+            10 => Self::AaCompromise,
+            _ => return None,
+        })
+    }
+
+    pub fn code(&self) -> u8 {
+        *self as u8
+    }
+}
+
+/// 输出RFC 5280中的名称，如 "keyCompromise"
+impl fmt::Display for X509CrlReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Unspecified => "unspecified",
+            Self::KeyCompromise => "keyCompromise",
+            Self::CaCompromise => "cACompromise",
+            Self::AffiliationChanged => "affiliationChanged",
+            Self::Superseded => "superseded",
+            Self::CessationOfOperation => "cessationOfOperation",
+            Self::CertificateHold => "certificateHold",
+            Self::RemoveFromCrl => "removeFromCRL",
+            Self::PrivilegeWithdrawn => "privilegeWithdrawn",
+            Self::AaCompromise => "aACompromise",
+        })
+    }
+}
+
+impl X509Crl {
+    /// 解析DER编码的CRL
+    ///
+    /// 签名算法不是SM2-with-SM3、reasonCode非法或v1 CRL带有扩展时返回None
+    pub fn from_der(der: &[u8]) -> Option<Self> {
+        let mut outer = DerReader::new(der);
+        let mut crl = outer.read_sequence()?;
+        outer.finish()?;
+
+        let tbs = crl.read_raw(TAG_SEQUENCE)?;
+        let signature_algorithm = crl.read_raw(TAG_SEQUENCE)?;
+        let signature = crl.read_bit_string()?;
+        crl.finish()?;
+        check_signature_algorithm(signature_algorithm)?;
+
+        let mut outer = DerReader::new(tbs);
+        let mut fields = outer.read_sequence()?;
+        outer.finish()?;
+
+        // version INTEGER OPTIONAL，只能是 v2(1)
+        let version = match fields.peek_tag() {
+            Some(TAG_INTEGER) => match fields.read_unsigned_integer()? {
+                [1] => 2,
+                _ => return None,
+            },
+            _ => 1,
+        };
+        if fields.read_raw(TAG_SEQUENCE)? != signature_algorithm {
+            return None;
+        }
+        let issuer = X509Name::from_der(fields.read_raw(TAG_SEQUENCE)?)?;
+        let this_update = fields.read_time()?;
+        let next_update = match fields.peek_tag() {
+            Some(TAG_UTC_TIME | TAG_GENERALIZED_TIME) => Some(fields.read_time()?),
+            _ => None,
+        };
+
+        let mut revoked = Vec::new();
+        if fields.peek_tag() == Some(TAG_SEQUENCE) {
+            let mut entries = fields.read_sequence()?;
+            while !entries.is_empty() {
+                revoked.push(parse_entry(entries.read_sequence()?, version)?);
+            }
+        }
+        let extensions = match fields.read_optional(TAG_CONTEXT) {
+            Some(value) if version == 2 => parse_extensions(value)?,
+            Some(_) => return None,
+            None => Vec::new(),
+        };
+        fields.finish()?;
+
+        Some(Self {
+            der: der.to_vec(),
+            tbs: tbs.to_vec(),
+            version,
+            issuer,
+            this_update,
+            next_update,
+            revoked,
+            extensions,
+            signature: signature.to_vec(),
+        })
+    }
+
+    /// 解析 `-----BEGIN X509 CRL-----` PEM
+    pub fn from_pem(pem: &str) -> Option<Self> {
+        let (label, der) = pem_decode(pem)?;
+        if label != PEM_CRL {
+            return None;
+        }
+        Self::from_der(&der)
+    }
+
+    pub fn as_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    pub fn to_pem(&self) -> String {
+        pem_encode(PEM_CRL, &self.der)
+    }
+
+    /// 被签名的tbsCertList编码
+    pub fn tbs_der(&self) -> &[u8] {
+        &self.tbs
+    }
+
+    /// CRL版本，1或2
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub fn issuer(&self) -> &X509Name {
+        &self.issuer
+    }
+
+    /// 本次更新时间，Unix时间戳（秒）
+    pub fn this_update(&self) -> i64 {
+        self.this_update
+    }
+
+    /// 下次更新时间，Unix时间戳（秒）
+    pub fn next_update(&self) -> Option<i64> {
+        self.next_update
+    }
+
+    /// 给定时刻是否在 thisUpdate 与 nextUpdate 之间（含端点），没有nextUpdate时不设上限
+    pub fn is_valid_at(&self, unix: i64) -> bool {
+        self.this_update <= unix && self.next_update.is_none_or(|next| unix <= next)
+    }
+
+    pub fn revoked_certificates(&self) -> &[X509RevokedCertificate] {
+        &self.revoked
+    }
+
+    pub fn extensions(&self) -> &[X509Extension] {
+        &self.extensions
+    }
+
+    /// 按点分OID查找CRL扩展
+    pub fn extension(&self, oid: &str) -> Option<&X509Extension> {
+        self.extensions.iter().find(|e| e.oid == oid)
+    }
+
+    /// cRLNumber 扩展的INTEGER内容字节
+    pub fn crl_number(&self) -> Option<Vec<u8>> {
+        let mut reader = DerReader::new(&self.extension(OID_CRL_NUMBER)?.value);
+        let number = reader.read_unsigned_integer()?;
+        reader.finish()?;
+        Some(number.to_vec())
+    }
+
+    /// authorityKeyIdentifier 中的 keyIdentifier
+    pub fn authority_key_identifier(&self) -> Option<Vec<u8>> {
+        parse_authority_key_identifier(&self.extension(OID_AUTHORITY_KEY_IDENTIFIER)?.value)?
+    }
+
+    /// DER编码的SM2签名值
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// 按序列号查找吊销记录，序列号为INTEGER的内容字节，须与记录逐字节一致（RFC 5280）
+    pub fn revocation(&self, serial_number: &[u8]) -> Option<&X509RevokedCertificate> {
+        self.revoked.iter().find(|entry| entry.serial_number == serial_number)
+    }
+
+    /// 证书是否列在本CRL中，不检查签发者
+    pub fn is_revoked(&self, certificate: &X509Certificate) -> bool {
+        self.revocation(certificate.serial_number()).is_some()
+    }
+
+    /// 用签发者公钥验证CRL签名，按GM/T 0015使用默认用户身份标识
+    pub fn verify_signature(&self, issuer_key: &Sm2PublicKey) -> bool {
+        self.verify_signature_with_id(issuer_key, SM2_DEFAULT_ID)
+    }
+
+    /// 用指定的签名者身份标识验证CRL签名
+    pub fn verify_signature_with_id(&self, issuer_key: &Sm2PublicKey, id: &[u8]) -> bool {
+        Sm2Signature::from_der(&self.signature)
+            .is_some_and(|signature| issuer_key.verify_with_id(id, &self.tbs, &signature))
+    }
+
+    /// 检查本CRL由issuer签发：签发者名称一致且签名有效
+    pub fn verify_issued_by(&self, issuer: &X509Certificate) -> bool {
+        self.issuer == *issuer.subject() && self.verify_signature(&issuer.public_key())
+    }
+
+    /// 是否含有无法处理的关键扩展，这样的CRL不能用于吊销检查
+    pub(crate) fn has_unsupported_critical_extension(&self) -> bool {
+        let crl_extensions = self
+            .extensions
+            .iter()
+            .any(|e| e.critical && e.oid != OID_CRL_NUMBER && e.oid != OID_AUTHORITY_KEY_IDENTIFIER);
+        let entry_extensions = self
+            .revoked
+            .iter()
+            .flat_map(|entry| &entry.extensions)
+            .any(|e| e.critical && e.oid != OID_CRL_REASON && e.oid != OID_INVALIDITY_DATE);
+        crl_extensions || entry_extensions
+    }
+}
+
+/// SEQUENCE { userCertificate, revocationDate, crlEntryExtensions OPTIONAL }
+fn parse_entry(mut entry: DerReader, version: u8) -> Option<X509RevokedCertificate> {
+    let serial_number = entry.read(TAG_INTEGER)?;
+    if serial_number.is_empty() {
+        return None;
+    }
+    let revocation_date = entry.read_time()?;
+    let extensions = match entry.peek_tag() {
+        Some(TAG_SEQUENCE) if version == 2 => parse_extensions(entry.read_raw(TAG_SEQUENCE)?)?,
+        Some(_) => return None,
+        None => Vec::new(),
+    };
+    entry.finish()?;
+    let reason = match extensions.iter().find(|e| e.oid == OID_CRL_REASON) {
+        Some(extension) => {
+            let mut reader = DerReader::new(&extension.value);
+            let code = match reader.read(TAG_ENUMERATED)? {
+                [code] => *code,
+                _ => return None,
+            };
+            reader.finish()?;
+            Some(X509CrlReason::from_code(code)?)
+        }
+        None => None,
+    };
+    Some(X509RevokedCertificate { serial_number: serial_number.to_vec(), revocation_date, reason, extensions })
+}
+
+impl fmt::Debug for X509Crl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X509Crl")
+            .field("issuer", &self.issuer)
+            .field("this_update", &self.this_update)
+            .field("next_update", &self.next_update)
+            .field("revoked", &self.revoked.len())
+            .finish_non_exhaustive()
+    }
+}
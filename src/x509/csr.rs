@@ -0,0 +1,147 @@
+// PKCS#10 证书请求（RFC 2986）
+
+use super::extension::{X509Extension, parse_extensions};
+use super::name::X509Name;
+use super::{check_signature_algorithm, sign_structure};
+use crate::asn1::{
+    DerReader, OID_EXTENSION_REQUEST, TAG_CONTEXT, TAG_OID, TAG_SEQUENCE, TAG_SET, encode_oid, encode_sequence,
+    encode_tlv, encode_unsigned_integer, pem_decode, pem_encode,
+};
+use crate::sm2::{Sm2PrivateKey, Sm2PublicKey, Sm2Signature};
+
+const PEM_CERTIFICATE_REQUEST: &str = "CERTIFICATE REQUEST";
+
+/// 以SM2-with-SM3自签的证书请求
+#[derive(Clone, PartialEq, Eq)]
+pub struct X509Csr {
+    der: Vec<u8>,
+    info: Vec<u8>,
+    subject: X509Name,
+    public_key: Sm2PublicKey,
+    extensions: Vec<X509Extension>,
+    signature: Vec<u8>,
+}
+
+/// 证书请求构造器
+#[derive(Clone, Debug)]
+pub struct X509CsrBuilder {
+    subject: X509Name,
+    extensions: Vec<X509Extension>,
+}
+
+impl X509Csr {
+    /// 解析DER编码的证书请求，extensionRequest以外的属性被忽略
+    pub fn from_der(der: &[u8]) -> Option<Self> {
+        let mut outer = DerReader::new(der);
+        let mut request = outer.read_sequence()?;
+        outer.finish()?;
+
+        let info = request.read_raw(TAG_SEQUENCE)?;
+        check_signature_algorithm(request.read_raw(TAG_SEQUENCE)?)?;
+        let signature = request.read_bit_string()?;
+        request.finish()?;
+
+        let mut outer = DerReader::new(info);
+        let mut fields = outer.read_sequence()?;
+        if fields.read_unsigned_integer()? != [0] {
+            return None;
+        }
+        let subject = X509Name::from_der(fields.read_raw(TAG_SEQUENCE)?)?;
+        let public_key = Sm2PublicKey::from_public_key_der(fields.read_raw(TAG_SEQUENCE)?)?;
+
+        // attributes [0] IMPLICIT SET OF Attribute
+        let mut attributes = DerReader::new(fields.read(TAG_CONTEXT)?);
+        fields.finish()?;
+        let mut extensions = Vec::new();
+        while !attributes.is_empty() {
+            let mut attribute = attributes.read_sequence()?;
+            let oid = attribute.read(TAG_OID)?;
+            let mut values = DerReader::new(attribute.read(TAG_SET)?);
+            attribute.finish()?;
+            if oid == OID_EXTENSION_REQUEST {
+                extensions = parse_extensions(values.read_raw(TAG_SEQUENCE)?)?;
+                values.finish()?;
+            }
+        }
+
+        Some(Self {
+            der: der.to_vec(),
+            info: info.to_vec(),
+            subject,
+            public_key,
+            extensions,
+            signature: signature.to_vec(),
+        })
+    }
+
+    /// 解析 `-----BEGIN CERTIFICATE REQUEST-----` PEM，也接受旧的 `NEW CERTIFICATE REQUEST` 标签
+    pub fn from_pem(pem: &str) -> Option<Self> {
+        let (label, der) = pem_decode(pem)?;
+        match label.as_str() {
+            PEM_CERTIFICATE_REQUEST | "NEW CERTIFICATE REQUEST" => Self::from_der(&der),
+            _ => None,
+        }
+    }
+
+    pub fn as_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    pub fn to_pem(&self) -> String {
+        pem_encode(PEM_CERTIFICATE_REQUEST, &self.der)
+    }
+
+    pub fn subject(&self) -> &X509Name {
+        &self.subject
+    }
+
+    pub fn public_key(&self) -> Sm2PublicKey {
+        self.public_key
+    }
+
+    /// extensionRequest 属性中请求的扩展
+    pub fn extensions(&self) -> &[X509Extension] {
+        &self.extensions
+    }
+
+    /// 用请求中的公钥验证自签名（默认用户身份标识）
+    pub fn verify(&self) -> bool {
+        Sm2Signature::from_der(&self.signature).is_some_and(|signature| self.public_key.verify(&self.info, &signature))
+    }
+}
+
+impl std::fmt::Debug for X509Csr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("X509Csr").field("subject", &self.subject).finish_non_exhaustive()
+    }
+}
+
+impl X509CsrBuilder {
+    pub fn new(subject: X509Name) -> Self {
+        Self { subject, extensions: Vec::new() }
+    }
+
+    /// 添加请求的扩展，同一OID的扩展会被替换
+    pub fn extension(mut self, extension: X509Extension) -> Self {
+        self.extensions.retain(|e| e.oid != extension.oid);
+        self.extensions.push(extension);
+        self
+    }
+
+    /// 用私钥签名生成证书请求，扩展编码非法时返回None
+    pub fn sign(&self, key: &Sm2PrivateKey) -> Option<X509Csr> {
+        let mut attributes = Vec::new();
+        if !self.extensions.is_empty() {
+            let extensions = self.extensions.iter().map(X509Extension::to_der).collect::<Option<Vec<_>>>()?;
+            let extensions = encode_sequence(&extensions.iter().map(Vec::as_slice).collect::<Vec<_>>());
+            attributes = encode_sequence(&[&encode_oid(OID_EXTENSION_REQUEST), &encode_tlv(TAG_SET, &extensions)]);
+        }
+        let info = encode_sequence(&[
+            &encode_unsigned_integer(&[0]),
+            self.subject.as_der(),
+            &key.public_key().to_public_key_der(),
+            &encode_tlv(TAG_CONTEXT, &attributes),
+        ]);
+        X509Csr::from_der(&sign_structure(&info, key))
+    }
+}
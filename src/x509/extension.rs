@@ -0,0 +1,302 @@
+// X.509 v3 扩展及常用扩展的解析
+
+use super::name::X509Name;
+use crate::asn1::{
+    DerReader, TAG_BIT_STRING, TAG_BOOLEAN, TAG_CONTEXT, TAG_CONTEXT_PRIMITIVE, TAG_INTEGER, TAG_OCTET_STRING, TAG_OID,
+    TAG_SEQUENCE, encode_boolean, encode_oid, encode_sequence, encode_tlv, encode_unsigned_integer, oid_from_string,
+    oid_to_string,
+};
+
+pub(crate) const OID_SUBJECT_KEY_IDENTIFIER: &str = "2.5.29.14";
+pub(crate) const OID_KEY_USAGE: &str = "2.5.29.15";
+pub(crate) const OID_SUBJECT_ALT_NAME: &str = "2.5.29.17";
+pub(crate) const OID_BASIC_CONSTRAINTS: &str = "2.5.29.19";
+pub(crate) const OID_EXTENDED_KEY_USAGE: &str = "2.5.29.37";
+pub(crate) const OID_AUTHORITY_KEY_IDENTIFIER: &str = "2.5.29.35";
+
+/// 单个扩展，value 为 extnValue 中OCTET STRING的内容
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X509Extension {
+    /// 点分十进制OID
+    pub oid: String,
+    pub critical: bool,
+    pub value: Vec<u8>,
+}
+
+/// basicConstraints 扩展
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct X509BasicConstraints {
+    pub ca: bool,
+    /// 其下最多还能有几级中间CA
+    pub path_len: Option<u32>,
+}
+
+/// keyUsage 扩展，第i位对应 KeyUsage 的第i个命名位
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct X509KeyUsage(pub u16);
+
+impl X509KeyUsage {
+    pub const DIGITAL_SIGNATURE: Self = Self(1 << 0);
+    pub const NON_REPUDIATION: Self = Self(1 << 1);
+    pub const KEY_ENCIPHERMENT: Self = Self(1 << 2);
+    pub const DATA_ENCIPHERMENT: Self = Self(1 << 3);
+    pub const KEY_AGREEMENT: Self = Self(1 << 4);
+    pub const KEY_CERT_SIGN: Self = Self(1 << 5);
+    pub const CRL_SIGN: Self = Self(1 << 6);
+    pub const ENCIPHER_ONLY: Self = Self(1 << 7);
+    pub const DECIPHER_ONLY: Self = Self(1 << 8);
+
+    /// 是否包含other中的全部位
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for X509KeyUsage {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// GeneralName 中常用的几种形式
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum X509GeneralName {
+    Email(String),
+    Dns(String),
+    DirectoryName(X509Name),
+    Uri(String),
+    /// 4字节IPv4或16字节IPv6地址
+    Ip(Vec<u8>),
+    /// 其他形式，保留tag与内容
+    Other(u8, Vec<u8>),
+}
+
+impl X509Extension {
+    /// 编码为 Extension ::= SEQUENCE { extnID, critical DEFAULT FALSE, extnValue }
+    pub(crate) fn to_der(&self) -> Option<Vec<u8>> {
+        let oid = encode_oid(&oid_from_string(&self.oid)?);
+        let critical = if self.critical { encode_boolean(true) } else { Vec::new() };
+        Some(encode_sequence(&[&oid, &critical, &encode_tlv(TAG_OCTET_STRING, &self.value)]))
+    }
+
+    /// basicConstraints，CA证书按RFC 5280标为关键扩展
+    pub fn basic_constraints(constraints: X509BasicConstraints) -> Self {
+        let ca = if constraints.ca { encode_boolean(true) } else { Vec::new() };
+        let path_len = constraints.path_len.map_or(Vec::new(), |len| encode_unsigned_integer(&len.to_be_bytes()));
+        Self {
+            oid: OID_BASIC_CONSTRAINTS.to_string(),
+            critical: constraints.ca,
+            value: encode_sequence(&[&ca, &path_len]),
+        }
+    }
+
+    /// keyUsage，关键扩展；RFC 5280要求至少设置一位，空用途返回None
+    pub fn key_usage(usage: X509KeyUsage) -> Option<Self> {
+        if usage.0 == 0 {
+            return None;
+        }
+        // 命名位列表按DER去掉末尾的0位
+        let bits = [(usage.0 as u8).reverse_bits(), ((usage.0 >> 8) as u8).reverse_bits()];
+        let len = if bits[1] != 0 { 2 } else { 1 };
+        let unused = bits[len - 1].trailing_zeros() as u8;
+        let mut content = vec![unused];
+        content.extend_from_slice(&bits[..len]);
+        Some(Self { oid: OID_KEY_USAGE.to_string(), critical: true, value: encode_tlv(TAG_BIT_STRING, &content) })
+    }
+
+    /// extendedKeyUsage，参数为点分OID，OID非法时返回None
+    pub fn extended_key_usage(purposes: &[&str]) -> Option<Self> {
+        let oids = purposes.iter().map(|oid| Some(encode_oid(&oid_from_string(oid)?))).collect::<Option<Vec<_>>>()?;
+        let oids: Vec<&[u8]> = oids.iter().map(|oid| oid.as_slice()).collect();
+        Some(Self { oid: OID_EXTENDED_KEY_USAGE.to_string(), critical: false, value: encode_sequence(&oids) })
+    }
+
+    /// subjectAltName，IP地址须为4或16字节
+    pub fn subject_alt_names(names: &[X509GeneralName]) -> Option<Self> {
+        let mut encoded = Vec::with_capacity(names.len());
+        for name in names {
+            encoded.push(match name {
+                X509GeneralName::Email(email) if email.is_ascii() => encode_tlv(0x81, email.as_bytes()),
+                X509GeneralName::Dns(dns) if dns.is_ascii() => encode_tlv(0x82, dns.as_bytes()),
+                X509GeneralName::DirectoryName(name) => encode_tlv(0xA4, name.as_der()),
+                X509GeneralName::Uri(uri) if uri.is_ascii() => encode_tlv(0x86, uri.as_bytes()),
+                X509GeneralName::Ip(ip) if ip.len() == 4 || ip.len() == 16 => encode_tlv(0x87, ip),
+                X509GeneralName::Other(tag, value) => encode_tlv(*tag, value),
+                _ => return None,
+            });
+        }
+        let encoded: Vec<&[u8]> = encoded.iter().map(|name| name.as_slice()).collect();
+        Some(Self { oid: OID_SUBJECT_ALT_NAME.to_string(), critical: false, value: encode_sequence(&encoded) })
+    }
+
+    pub fn subject_key_identifier(key_id: &[u8]) -> Self {
+        Self {
+            oid: OID_SUBJECT_KEY_IDENTIFIER.to_string(),
+            critical: false,
+            value: encode_tlv(TAG_OCTET_STRING, key_id),
+        }
+    }
+
+    /// authorityKeyIdentifier，只包含 keyIdentifier
+    pub fn authority_key_identifier(key_id: &[u8]) -> Self {
+        Self {
+            oid: OID_AUTHORITY_KEY_IDENTIFIER.to_string(),
+            critical: false,
+            value: encode_sequence(&[&encode_tlv(TAG_CONTEXT_PRIMITIVE, key_id)]),
+        }
+    }
+}
+
+/// 解析 Extensions ::= SEQUENCE SIZE (1..MAX) OF Extension，拒绝重复扩展与格式错误的已知扩展
+pub(crate) fn parse_extensions(der: &[u8]) -> Option<Vec<X509Extension>> {
+    let mut outer = DerReader::new(der);
+    let mut seq = outer.read_sequence()?;
+    outer.finish()?;
+
+    let mut extensions: Vec<X509Extension> = Vec::new();
+    while !seq.is_empty() {
+        let mut extension = seq.read_sequence()?;
+        let oid = oid_to_string(extension.read(TAG_OID)?)?;
+        let critical = if extension.peek_tag() == Some(TAG_BOOLEAN) { extension.read_boolean()? } else { false };
+        let value = extension.read(TAG_OCTET_STRING)?.to_vec();
+        extension.finish()?;
+        if extensions.iter().any(|e| e.oid == oid) {
+            return None;
+        }
+        let extension = X509Extension { oid, critical, value };
+        if !validate(&extension) {
+            return None;
+        }
+        extensions.push(extension);
+    }
+    if extensions.is_empty() {
+        return None;
+    }
+    Some(extensions)
+}
+
+/// 已知扩展须能正确解析
+fn validate(extension: &X509Extension) -> bool {
+    let value = &extension.value;
+    match extension.oid.as_str() {
+        OID_BASIC_CONSTRAINTS => parse_basic_constraints(value).is_some(),
+        OID_KEY_USAGE => parse_key_usage(value).is_some(),
+        OID_EXTENDED_KEY_USAGE => parse_extended_key_usage(value).is_some(),
+        OID_SUBJECT_KEY_IDENTIFIER => parse_subject_key_identifier(value).is_some(),
+        OID_AUTHORITY_KEY_IDENTIFIER => parse_authority_key_identifier(value).is_some(),
+        OID_SUBJECT_ALT_NAME => parse_general_names(value).is_some(),
+        _ => true,
+    }
+}
+
+/// 路径验证能够处理的扩展，其余关键扩展会导致验证失败
+pub(crate) fn is_supported(oid: &str) -> bool {
+    matches!(
+        oid,
+        OID_BASIC_CONSTRAINTS
+            | OID_KEY_USAGE
+            | OID_EXTENDED_KEY_USAGE
+            | OID_SUBJECT_KEY_IDENTIFIER
+            | OID_AUTHORITY_KEY_IDENTIFIER
+            | OID_SUBJECT_ALT_NAME
+    )
+}
+
+/// BasicConstraints ::= SEQUENCE { cA BOOLEAN DEFAULT FALSE, pathLenConstraint INTEGER OPTIONAL }
+pub(crate) fn parse_basic_constraints(der: &[u8]) -> Option<X509BasicConstraints> {
+    let mut outer = DerReader::new(der);
+    let mut seq = outer.read_sequence()?;
+    outer.finish()?;
+    let ca = if seq.peek_tag() == Some(TAG_BOOLEAN) { seq.read_boolean()? } else { false };
+    let path_len = match seq.peek_tag() {
+        Some(TAG_INTEGER) => {
+            let value = seq.read_unsigned_integer()?;
+            if value.len() > 4 {
+                return None;
+            }
+            Some(value.iter().fold(0u32, |acc, &b| (acc << 8) | b as u32))
+        }
+        _ => None,
+    };
+    seq.finish()?;
+    Some(X509BasicConstraints { ca, path_len })
+}
+
+/// KeyUsage ::= BIT STRING，允许末尾未用位
+pub(crate) fn parse_key_usage(der: &[u8]) -> Option<X509KeyUsage> {
+    let mut reader = DerReader::new(der);
+    let value = reader.read(TAG_BIT_STRING)?;
+    reader.finish()?;
+    let (&unused, bits) = value.split_first()?;
+    if unused > 7 || bits.is_empty() || bits.len() > 2 || bits[bits.len() - 1] & ((1u8 << unused) - 1) != 0 {
+        return None;
+    }
+    // 第i个命名位位于第 i/8 字节的第 7 - i%8 位
+    let mut usage = 0u16;
+    for (i, &byte) in bits.iter().enumerate() {
+        usage |= (byte.reverse_bits() as u16) << (8 * i);
+    }
+    Some(X509KeyUsage(usage))
+}
+
+/// ExtKeyUsageSyntax ::= SEQUENCE SIZE (1..MAX) OF KeyPurposeId
+pub(crate) fn parse_extended_key_usage(der: &[u8]) -> Option<Vec<String>> {
+    let mut outer = DerReader::new(der);
+    let mut seq = outer.read_sequence()?;
+    outer.finish()?;
+    let mut purposes = Vec::new();
+    while !seq.is_empty() {
+        purposes.push(oid_to_string(seq.read(TAG_OID)?)?);
+    }
+    if purposes.is_empty() { None } else { Some(purposes) }
+}
+
+/// SubjectKeyIdentifier ::= OCTET STRING
+pub(crate) fn parse_subject_key_identifier(der: &[u8]) -> Option<Vec<u8>> {
+    let mut reader = DerReader::new(der);
+    let value = reader.read(TAG_OCTET_STRING)?;
+    reader.finish()?;
+    Some(value.to_vec())
+}
+
+/// AuthorityKeyIdentifier 中的 keyIdentifier [0]，其余字段只检查格式
+pub(crate) fn parse_authority_key_identifier(der: &[u8]) -> Option<Option<Vec<u8>>> {
+    let mut outer = DerReader::new(der);
+    let mut seq = outer.read_sequence()?;
+    outer.finish()?;
+    let key_id = seq.read_optional(TAG_CONTEXT_PRIMITIVE).map(|id| id.to_vec());
+    seq.read_optional(TAG_CONTEXT | 1);
+    seq.read_optional(TAG_CONTEXT_PRIMITIVE | 2);
+    seq.finish()?;
+    Some(key_id)
+}
+
+/// GeneralNames ::= SEQUENCE SIZE (1..MAX) OF GeneralName
+pub(crate) fn parse_general_names(der: &[u8]) -> Option<Vec<X509GeneralName>> {
+    let mut outer = DerReader::new(der);
+    let mut seq = outer.read_sequence()?;
+    outer.finish()?;
+    let mut names = Vec::new();
+    while !seq.is_empty() {
+        // rfc822Name [1]、dNSName [2]、directoryName [4]、URI [6]、iPAddress [7]
+        let (tag, value) = seq.read_any()?;
+        let ascii = || value.is_ascii().then(|| String::from_utf8(value.to_vec()).unwrap());
+        let name = match tag {
+            0x81 => X509GeneralName::Email(ascii()?),
+            0x82 => X509GeneralName::Dns(ascii()?),
+            0xA4 => {
+                let mut inner = DerReader::new(value);
+                let name = X509Name::from_der(inner.read_raw(TAG_SEQUENCE)?)?;
+                inner.finish()?;
+                X509GeneralName::DirectoryName(name)
+            }
+            0x86 => X509GeneralName::Uri(ascii()?),
+            0x87 if value.len() == 4 || value.len() == 16 => X509GeneralName::Ip(value.to_vec()),
+            0x87 => return None,
+            _ => X509GeneralName::Other(tag, value.to_vec()),
+        };
+        names.push(name);
+    }
+    if names.is_empty() { None } else { Some(names) }
+}
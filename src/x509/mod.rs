@@ -0,0 +1,53 @@
+// 国密X.509证书（GM/T 0015）
+//
+// 仅支持SM2公钥与SM2-with-SM3签名（OID 1.2.156.10197.1.501），签名时使用默认用户身份标识
+
+mod builder;
+mod certificate;
+mod crl;
+mod csr;
+mod extension;
+mod name;
+mod path;
+mod trust_store;
+
+use crate::asn1::{DerReader, OID_SM2_WITH_SM3, TAG_NULL, encode_bit_string, encode_oid, encode_sequence};
+use crate::sm2::{Sm2PointFormat, Sm2PrivateKey, Sm2PublicKey};
+use crate::sm3::sm3_hash;
+
+pub use builder::X509CertificateBuilder;
+pub use certificate::X509Certificate;
+pub use crl::{X509Crl, X509CrlReason, X509RevokedCertificate};
+pub use csr::{X509Csr, X509CsrBuilder};
+pub use extension::{X509BasicConstraints, X509Extension, X509GeneralName, X509KeyUsage};
+pub use name::X509Name;
+pub use path::{X509PathError, X509PathErrorKind, X509PathValidator};
+pub use trust_store::X509TrustStore;
+
+/// 检查AlgorithmIdentifier编码为 { sm2-with-sm3 }，参数可省略或为NULL
+fn check_signature_algorithm(der: &[u8]) -> Option<()> {
+    let mut reader = DerReader::new(der);
+    let mut seq = reader.read_sequence()?;
+    seq.expect_oid(OID_SM2_WITH_SM3)?;
+    if seq.read_optional(TAG_NULL).is_some_and(|null| !null.is_empty()) {
+        return None;
+    }
+    seq.finish()?;
+    reader.finish()
+}
+
+/// 签名算法标识 { sm2-with-sm3 }，与OpenSSL、GmSSL一致省略参数
+fn signature_algorithm() -> Vec<u8> {
+    encode_sequence(&[&encode_oid(OID_SM2_WITH_SM3)])
+}
+
+/// 对待签结构签名并组装 SEQUENCE { tbs, signatureAlgorithm, signature }
+fn sign_structure(tbs: &[u8], key: &Sm2PrivateKey) -> Vec<u8> {
+    let signature = key.sign(tbs).to_der();
+    encode_sequence(&[tbs, &signature_algorithm(), &encode_bit_string(&signature)])
+}
+
+/// 密钥标识：SM3(subjectPublicKey) 的前160位（参照RFC 7093方法1）
+fn key_identifier(public_key: &Sm2PublicKey) -> Vec<u8> {
+    sm3_hash(&public_key.to_sec1_bytes(Sm2PointFormat::Uncompressed))[..20].to_vec()
+}
@@ -0,0 +1,144 @@
+// X.509 Name（RDNSequence）
+
+use crate::asn1::{
+    DerReader, TAG_BMP_STRING, TAG_IA5_STRING, TAG_OID, TAG_PRINTABLE_STRING, TAG_SET, TAG_TELETEX_STRING,
+    TAG_UTF8_STRING, encode_oid, encode_sequence, encode_tlv, oid_from_string, oid_to_string,
+};
+use std::fmt;
+
+/// 常见属性的OID与RFC 4514简称
+const SHORT_NAMES: &[(&str, &str)] = &[
+    ("2.5.4.3", "CN"),
+    ("2.5.4.5", "serialNumber"),
+    ("2.5.4.6", "C"),
+    ("2.5.4.7", "L"),
+    ("2.5.4.8", "ST"),
+    ("2.5.4.10", "O"),
+    ("2.5.4.11", "OU"),
+    ("1.2.840.113549.1.9.1", "emailAddress"),
+];
+
+/// X.509 名称，保留原始DER用于比较
+#[derive(Clone, PartialEq, Eq)]
+pub struct X509Name {
+    der: Vec<u8>,
+    attributes: Vec<(String, String)>,
+}
+
+impl X509Name {
+    /// 由 (属性, 值) 列表构造名称，每个属性单独成为一个RDN
+    ///
+    /// 属性可以是简称（CN、O、OU、C、ST、L、serialNumber、emailAddress）或点分OID。
+    /// C与serialNumber编码为PrintableString，emailAddress编码为IA5String，其余为UTF8String；
+    /// 属性未知、值为空或不符合字符串类型时返回None
+    pub fn from_attributes(attributes: &[(&str, &str)]) -> Option<Self> {
+        let mut rdns = Vec::with_capacity(attributes.len());
+        for &(name, value) in attributes {
+            let oid = SHORT_NAMES.iter().find(|(_, n)| *n == name).map_or(name, |(oid, _)| oid);
+            let oid_bytes = oid_from_string(oid)?;
+            let tag = match oid {
+                "2.5.4.6" | "2.5.4.5" => TAG_PRINTABLE_STRING,
+                "1.2.840.113549.1.9.1" => TAG_IA5_STRING,
+                _ => TAG_UTF8_STRING,
+            };
+            let valid = match tag {
+                TAG_PRINTABLE_STRING => value.bytes().all(is_printable),
+                TAG_IA5_STRING => value.is_ascii(),
+                _ => true,
+            };
+            if value.is_empty() || !valid {
+                return None;
+            }
+            let attribute = encode_sequence(&[&encode_oid(&oid_bytes), &encode_tlv(tag, value.as_bytes())]);
+            rdns.push(encode_tlv(TAG_SET, &attribute));
+        }
+        let rdns: Vec<&[u8]> = rdns.iter().map(|rdn| rdn.as_slice()).collect();
+        Self::from_der(&encode_sequence(&rdns))
+    }
+
+    /// 解析完整的Name编码（SEQUENCE OF SET OF AttributeTypeAndValue）
+    pub(crate) fn from_der(der: &[u8]) -> Option<Self> {
+        let mut outer = DerReader::new(der);
+        let mut rdns = outer.read_sequence()?;
+        outer.finish()?;
+
+        let mut attributes = Vec::new();
+        while !rdns.is_empty() {
+            let mut set = DerReader::new(rdns.read(TAG_SET)?);
+            if set.is_empty() {
+                return None;
+            }
+            while !set.is_empty() {
+                let mut attribute = set.read_sequence()?;
+                let oid = oid_to_string(attribute.read(TAG_OID)?)?;
+                let value = decode_string(&mut attribute)?;
+                attribute.finish()?;
+                attributes.push((oid, value));
+            }
+        }
+        Some(Self { der: der.to_vec(), attributes })
+    }
+
+    /// 完整的DER编码
+    pub fn as_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// 按出现顺序排列的 (OID, 值)
+    pub fn attributes(&self) -> &[(String, String)] {
+        &self.attributes
+    }
+
+    /// 第一个指定OID的属性值
+    pub fn get(&self, oid: &str) -> Option<&str> {
+        self.attributes.iter().find(|(o, _)| o == oid).map(|(_, v)| v.as_str())
+    }
+
+    pub fn common_name(&self) -> Option<&str> {
+        self.get("2.5.4.3")
+    }
+}
+
+/// PrintableString 允许的字符
+fn is_printable(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || b" '()+,-./:=?".contains(&c)
+}
+
+/// 解析DirectoryString等字符串类型
+fn decode_string(reader: &mut DerReader) -> Option<String> {
+    let (tag, value) = reader.read_any()?;
+    match tag {
+        TAG_UTF8_STRING => String::from_utf8(value.to_vec()).ok(),
+        TAG_PRINTABLE_STRING | TAG_IA5_STRING => value.is_ascii().then(|| String::from_utf8(value.to_vec()).unwrap()),
+        // T61String 实际多按Latin-1使用
+        TAG_TELETEX_STRING => Some(value.iter().map(|&b| b as char).collect()),
+        TAG_BMP_STRING => {
+            if !value.len().is_multiple_of(2) {
+                return None;
+            }
+            let units: Vec<u16> = value.chunks(2).map(|c| u16::from_be_bytes([c[0], c[1]])).collect();
+            String::from_utf16(&units).ok()
+        }
+        _ => None,
+    }
+}
+
+/// 按 "CN=..., O=..." 的形式输出，未知属性使用点分OID
+impl fmt::Display for X509Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (oid, value)) in self.attributes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            let name = SHORT_NAMES.iter().find(|(o, _)| o == oid).map_or(oid.as_str(), |(_, n)| n);
+            write!(f, "{}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for X509Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "X509Name({})", self)
+    }
+}
@@ -0,0 +1,277 @@
+// 证书路径构建与验证（参照RFC 5280第6节的简化版本）
+//
+// 从终端证书出发，按签发者名称在信任锚与中间证书中查找上级证书，直到到达信任锚；
+// 对得到的每条候选路径依次检查签名、有效期、basicConstraints/pathLen、keyUsage与关键扩展，
+// 提供CRL时再检查签发者发布了CRL的各级证书的吊销状态
+
+use super::certificate::X509Certificate;
+use super::crl::{X509Crl, X509CrlReason};
+use super::extension::{X509KeyUsage, is_supported};
+use super::name::X509Name;
+use super::trust_store::X509TrustStore;
+use std::fmt;
+
+/// 路径中最多的证书数量（含终端证书与信任锚）
+const MAX_PATH_LENGTH: usize = 10;
+
+/// 路径验证器
+#[derive(Clone, Debug)]
+pub struct X509PathValidator<'a> {
+    trust_store: &'a X509TrustStore,
+    intermediates: &'a [X509Certificate],
+    crls: &'a [X509Crl],
+    require_crls: bool,
+    time: i64,
+    key_usage: Option<X509KeyUsage>,
+}
+
+/// 路径验证失败的检查项
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum X509PathErrorKind {
+    /// 找不到主体名称与本证书签发者名称一致的证书
+    IssuerNotFound,
+    /// 自签证书不在信任锚中
+    NotTrusted,
+    /// 本证书的签名无法用上级证书的公钥验证
+    Signature,
+    /// 验证时刻早于 notBefore
+    NotYetValid,
+    /// 验证时刻晚于 notAfter
+    Expired,
+    /// 用于签发证书但不是CA证书
+    NotCa,
+    /// 其下的中间CA数量超过 pathLenConstraint
+    PathLenExceeded,
+    /// keyUsage 不允许签发证书，或终端证书不满足要求的用途
+    KeyUsage,
+    /// 含有无法处理的关键扩展
+    UnsupportedCriticalExtension,
+    /// 已被签发者的CRL吊销，附带吊销原因
+    Revoked(Option<X509CrlReason>),
+    /// 签发者发布的CRL都不可用（签名无效、不在有效期内等），或严格模式下没有签发者发布的CRL
+    RevocationUnknown,
+    /// 路径超过最大长度
+    PathTooLong,
+}
+
+/// 路径验证错误：哪一项检查在哪一张证书上失败
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X509PathError {
+    pub kind: X509PathErrorKind,
+    /// 证书在路径中的位置，终端证书为0，向上依次加1
+    pub depth: usize,
+    /// 出错证书的主体名称
+    pub subject: X509Name,
+}
+
+impl<'a> X509PathValidator<'a> {
+    /// 以信任锚集合与验证时刻（Unix时间戳，秒）创建验证器
+    pub fn new(trust_store: &'a X509TrustStore, time: i64) -> Self {
+        Self { trust_store, intermediates: &[], crls: &[], require_crls: false, time, key_usage: None }
+    }
+
+    /// 可用于构建路径的中间证书，顺序任意，可以包含无关证书
+    pub fn intermediates(mut self, intermediates: &'a [X509Certificate]) -> Self {
+        self.intermediates = intermediates;
+        self
+    }
+
+    /// 用CRL检查吊销状态
+    ///
+    /// 只检查签发者（按名称）发布了CRL的证书：这些CRL中须至少有一个签名有效且在验证时刻有效，
+    /// 否则返回 `RevocationUnknown`；签发者没有发布CRL的证书不检查。吊销时间晚于验证时刻的记录不视为已吊销
+    pub fn crls(mut self, crls: &'a [X509Crl]) -> Self {
+        self.crls = crls;
+        self
+    }
+
+    /// 严格模式：路径上除信任锚外的每张证书都须有签发者发布的可用CRL，否则返回 `RevocationUnknown`
+    pub fn require_crls(mut self) -> Self {
+        self.require_crls = true;
+        self
+    }
+
+    /// 要求终端证书的keyUsage（若存在）包含这些用途
+    pub fn key_usage(mut self, key_usage: X509KeyUsage) -> Self {
+        self.key_usage = Some(key_usage);
+        self
+    }
+
+    /// 构建并验证证书路径，成功时返回从终端证书到信任锚的路径
+    ///
+    /// 存在多条候选路径时返回第一条通过验证的路径；全部失败时返回遇到的第一个错误
+    pub fn verify(&self, leaf: &X509Certificate) -> Result<Vec<X509Certificate>, X509PathError> {
+        let mut chain = vec![leaf];
+        self.search(&mut chain).map(|path| path.into_iter().cloned().collect())
+    }
+
+    /// 深度优先查找上级证书，chain 的最后一张证书尚未找到签发者
+    fn search<'b>(&'b self, chain: &mut Vec<&'b X509Certificate>) -> Result<Vec<&'b X509Certificate>, X509PathError> {
+        let certificate = *chain.last().unwrap();
+        let depth = chain.len() - 1;
+        if self.trust_store.contains(certificate) {
+            self.validate(chain)?;
+            return Ok(chain.clone());
+        }
+        if chain.len() >= MAX_PATH_LENGTH {
+            return Err(error(X509PathErrorKind::PathTooLong, depth, certificate));
+        }
+
+        let anchors = self.trust_store.certificates().iter().map(|c| (c, true));
+        let intermediates = self.intermediates.iter().map(|c| (c, false));
+        let mut first_error = None;
+        for (issuer, trusted) in anchors.chain(intermediates) {
+            if !is_candidate_issuer(certificate, issuer) || chain.iter().any(|c| c.as_der() == issuer.as_der()) {
+                continue;
+            }
+            let result = if !certificate.verify_signature(&issuer.public_key()) {
+                Err(error(X509PathErrorKind::Signature, depth, certificate))
+            } else {
+                chain.push(issuer);
+                let result = if trusted {
+                    self.validate(chain).map(|_| chain.clone())
+                } else {
+                    self.search(chain)
+                };
+                chain.pop();
+                result
+            };
+            match result {
+                Ok(path) => return Ok(path),
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        Err(first_error.unwrap_or_else(|| {
+            // 自签证书没有更上级的证书，只能是未被信任的根
+            let kind = if certificate.issuer() == certificate.subject() && certificate.is_self_signed() {
+                X509PathErrorKind::NotTrusted
+            } else {
+                X509PathErrorKind::IssuerNotFound
+            };
+            error(kind, depth, certificate)
+        }))
+    }
+
+    /// 检查已构建路径上除签名外的各项约束，签名在构建时已经验证
+    fn validate(&self, chain: &[&X509Certificate]) -> Result<(), X509PathError> {
+        let anchor = chain.len() - 1;
+        for (depth, &certificate) in chain.iter().enumerate() {
+            let fail = |kind| Err(error(kind, depth, certificate));
+            if self.time < certificate.not_before() {
+                return fail(X509PathErrorKind::NotYetValid);
+            }
+            if self.time > certificate.not_after() {
+                return fail(X509PathErrorKind::Expired);
+            }
+            if certificate.extensions().iter().any(|e| e.critical && !is_supported(&e.oid)) {
+                return fail(X509PathErrorKind::UnsupportedCriticalExtension);
+            }
+
+            if depth == 0 {
+                let required = self.key_usage.unwrap_or(X509KeyUsage(0));
+                if certificate.key_usage().is_some_and(|usage| !usage.contains(required)) {
+                    return fail(X509PathErrorKind::KeyUsage);
+                }
+                continue;
+            }
+
+            // 签发者：v1信任锚没有扩展，视为CA
+            match certificate.basic_constraints() {
+                Some(constraints) if constraints.ca => {
+                    // 本证书与终端证书之间的中间CA数量
+                    if constraints.path_len.is_some_and(|len| depth - 1 > len as usize) {
+                        return fail(X509PathErrorKind::PathLenExceeded);
+                    }
+                }
+                None if depth == anchor && certificate.version() == 1 => {}
+                _ => return fail(X509PathErrorKind::NotCa),
+            }
+            if certificate.key_usage().is_some_and(|usage| !usage.contains(X509KeyUsage::KEY_CERT_SIGN)) {
+                return fail(X509PathErrorKind::KeyUsage);
+            }
+        }
+
+        if self.require_crls || !self.crls.is_empty() {
+            for (depth, pair) in chain.windows(2).enumerate() {
+                self.check_revocation(pair[0], pair[1]).map_err(|kind| error(kind, depth, pair[0]))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 在签发者发布的可用CRL中查找证书
+    fn check_revocation(
+        &self,
+        certificate: &X509Certificate,
+        issuer: &X509Certificate,
+    ) -> Result<(), X509PathErrorKind> {
+        if !self.require_crls && !self.crls.iter().any(|crl| crl.issuer() == issuer.subject()) {
+            return Ok(());
+        }
+        // 签发者的keyUsage不含cRLSign时，其CRL都不可用
+        if issuer.key_usage().is_some_and(|usage| !usage.contains(X509KeyUsage::CRL_SIGN)) {
+            return Err(X509PathErrorKind::RevocationUnknown);
+        }
+        let mut checked = false;
+        for crl in self.crls {
+            if !crl.is_valid_at(self.time) || crl.has_unsupported_critical_extension() || !crl.verify_issued_by(issuer)
+            {
+                continue;
+            }
+            if let Some(entry) = crl.revocation(certificate.serial_number())
+                && entry.revocation_date <= self.time
+            {
+                return Err(X509PathErrorKind::Revoked(entry.reason));
+            }
+            checked = true;
+        }
+        if checked { Ok(()) } else { Err(X509PathErrorKind::RevocationUnknown) }
+    }
+}
+
+/// 名称链接：签发者名称须与上级证书的主体名称一致；双方都有密钥标识时还须一致
+fn is_candidate_issuer(certificate: &X509Certificate, issuer: &X509Certificate) -> bool {
+    if certificate.issuer() != issuer.subject() {
+        return false;
+    }
+    match (certificate.authority_key_identifier(), issuer.subject_key_identifier()) {
+        (Some(authority), Some(subject)) => authority == subject,
+        _ => true,
+    }
+}
+
+fn error(kind: X509PathErrorKind, depth: usize, certificate: &X509Certificate) -> X509PathError {
+    X509PathError { kind, depth, subject: certificate.subject().clone() }
+}
+
+impl fmt::Display for X509PathErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Revoked(Some(reason)) => return write!(f, "证书已被吊销（{}）", reason),
+            Self::Revoked(None) => "证书已被吊销",
+            Self::RevocationUnknown => "无法确定吊销状态",
+            Self::IssuerNotFound => "找不到签发者证书",
+            Self::NotTrusted => "自签证书不受信任",
+            Self::Signature => "签名验证失败",
+            Self::NotYetValid => "证书尚未生效",
+            Self::Expired => "证书已过期",
+            Self::NotCa => "签发者不是CA证书",
+            Self::PathLenExceeded => "超过路径长度约束",
+            Self::KeyUsage => "密钥用途不允许",
+            Self::UnsupportedCriticalExtension => "含有无法处理的关键扩展",
+            Self::PathTooLong => "证书路径过长",
+        })
+    }
+}
+
+/// 输出形如 "第1级证书（CN=Intermediate）：签发者不是CA证书"
+impl fmt::Display for X509PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "第{}级证书（{}）：{}", self.depth, self.subject, self.kind)
+    }
+}
+
+impl std::error::Error for X509PathError {}
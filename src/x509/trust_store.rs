@@ -0,0 +1,88 @@
+// 受信任根证书集合
+
+use super::certificate::X509Certificate;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// 证书目录中会被加载的文件扩展名
+const PEM_EXTENSIONS: &[&str] = &["pem", "crt", "cer"];
+
+/// 受信任的根证书（信任锚）集合
+#[derive(Clone, Debug, Default)]
+pub struct X509TrustStore {
+    anchors: Vec<X509Certificate>,
+}
+
+impl X509TrustStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 加入一个信任锚，已存在的相同证书不会重复加入
+    pub fn add(&mut self, certificate: X509Certificate) {
+        if !self.contains(&certificate) {
+            self.anchors.push(certificate);
+        }
+    }
+
+    /// 加入PEM文本中的全部证书，返回新加入的数量，任一证书非法时返回None且不加入任何证书
+    pub fn add_pem(&mut self, pem: &str) -> Option<usize> {
+        let certificates = X509Certificate::from_pem_all(pem)?;
+        let before = self.anchors.len();
+        for certificate in certificates {
+            self.add(certificate);
+        }
+        Some(self.anchors.len() - before)
+    }
+
+    /// 加载目录下所有 .pem/.crt/.cer 文件中的证书（不递归子目录），返回新加入的数量
+    ///
+    /// 文件不是UTF-8文本或含有非法证书时返回 `InvalidData` 错误，此时不加入任何证书
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> io::Result<usize> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let matches = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| PEM_EXTENSIONS.iter().any(|p| e.eq_ignore_ascii_case(p)));
+            if matches && path.is_file() {
+                paths.push(path);
+            }
+        }
+        // 按文件名排序，加载顺序与目录遍历顺序无关
+        paths.sort();
+
+        // 先解析全部文件，全部成功后再加入
+        let mut certificates = Vec::new();
+        for path in paths {
+            let text = fs::read_to_string(&path)?;
+            certificates.extend(X509Certificate::from_pem_all(&text).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{}: 无效的证书", path.display()))
+            })?);
+        }
+        let before = self.anchors.len();
+        for certificate in certificates {
+            self.add(certificate);
+        }
+        Ok(self.anchors.len() - before)
+    }
+
+    /// 证书是否为信任锚（DER编码完全一致）
+    pub fn contains(&self, certificate: &X509Certificate) -> bool {
+        self.anchors.iter().any(|anchor| anchor.as_der() == certificate.as_der())
+    }
+
+    pub fn certificates(&self) -> &[X509Certificate] {
+        &self.anchors
+    }
+
+    pub fn len(&self) -> usize {
+        self.anchors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anchors.is_empty()
+    }
+}
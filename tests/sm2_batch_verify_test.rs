@@ -0,0 +1,109 @@
+// SM2批量验签测试
+
+use gm_sdk::{
+    SM2_DEFAULT_ID, Sm2BatchVerifier, sm2_generate_keypair, sm2_message_digest, sm2_sign_digest,
+    sm2_sign_digest_recoverable,
+};
+
+struct Entry {
+    public_key: [u8; 64],
+    digest: [u8; 32],
+    signature: [u8; 64],
+}
+
+fn signed_entries(count: usize) -> Vec<Entry> {
+    (0..count)
+        .map(|i| {
+            let (private_key, public_key) = sm2_generate_keypair();
+            let message = format!("log record {}", i);
+            let digest = sm2_message_digest(&public_key, SM2_DEFAULT_ID, message.as_bytes()).unwrap();
+            let signature = sm2_sign_digest(&private_key, &digest);
+            Entry {
+                public_key,
+                digest,
+                signature,
+            }
+        })
+        .collect()
+}
+
+/// 每个批次对全部签名使用同一恢复标识，标识正确与否都不应影响结果
+fn batch_with_all_recovery_ids(entries: &[Entry]) -> Vec<Sm2BatchVerifier> {
+    (0..4u8)
+        .map(|recovery_id| {
+            let mut batch = Sm2BatchVerifier::new();
+            for entry in entries {
+                batch.push_with_recovery_id(&entry.public_key, &entry.digest, &entry.signature, recovery_id);
+            }
+            batch
+        })
+        .collect()
+}
+
+#[test]
+fn test_sm2_batch_verify_all_valid() {
+    let entries = signed_entries(8);
+    let mut batch = Sm2BatchVerifier::new();
+    assert!(batch.is_empty());
+    for entry in &entries {
+        batch.push(&entry.public_key, &entry.digest, &entry.signature);
+    }
+    assert_eq!(batch.len(), 8);
+    assert_eq!(batch.verify(), Ok(()));
+
+    // 恢复标识无论是否正确都不影响结果
+    for batch in batch_with_all_recovery_ids(&entries) {
+        assert_eq!(batch.verify(), Ok(()));
+    }
+}
+
+#[test]
+fn test_sm2_batch_verify_identifies_bad_signatures() {
+    let mut entries = signed_entries(6);
+    entries[1].signature[40] ^= 1;
+    entries[4].digest[0] ^= 1;
+
+    let mut batch = Sm2BatchVerifier::new();
+    for entry in &entries {
+        batch.push(&entry.public_key, &entry.digest, &entry.signature);
+    }
+    assert_eq!(batch.verify(), Err(vec![1, 4]));
+
+    for batch in batch_with_all_recovery_ids(&entries) {
+        assert_eq!(batch.verify(), Err(vec![1, 4]));
+    }
+}
+
+#[test]
+fn test_sm2_batch_verify_invalid_input() {
+    let entries = signed_entries(2);
+    let mut batch = Sm2BatchVerifier::new();
+    batch.push(&[0u8; 64], &entries[0].digest, &entries[0].signature);
+    batch.push_with_recovery_id(&entries[1].public_key, &entries[1].digest, &[0u8; 64], 0);
+    batch.push_with_recovery_id(&entries[0].public_key, &entries[0].digest, &entries[0].signature, 9);
+    assert_eq!(batch.verify(), Err(vec![0, 1]));
+
+    // 空批次视为全部有效
+    assert_eq!(Sm2BatchVerifier::new().verify(), Ok(()));
+}
+
+#[test]
+fn test_sm2_batch_verify_message() {
+    let id = b"ALICE123@YAHOO.COM";
+    let mut batch = Sm2BatchVerifier::new();
+    for i in 0..4 {
+        let (private_key, public_key) = sm2_generate_keypair();
+        let message = format!("log record {}", i);
+        let digest = sm2_message_digest(&public_key, id, message.as_bytes()).unwrap();
+        let (signature, recovery_id) = sm2_sign_digest_recoverable(&private_key, &digest);
+        // 第3条按默认ID计算e，与签名时的ID不一致
+        let signer_id: &[u8] = if i == 3 { SM2_DEFAULT_ID } else { id };
+        let message = message.as_bytes();
+        assert!(batch.push_message_with_recovery_id(&public_key, signer_id, message, &signature, recovery_id));
+    }
+    assert_eq!(batch.verify(), Err(vec![3]));
+
+    // 公钥不在曲线上时不加入
+    assert!(!batch.push_message_with_recovery_id(&[0u8; 64], id, b"message", &[1u8; 64], 0));
+    assert_eq!(batch.len(), 4);
+}
@@ -1,6 +1,5 @@
 // SM2 关键操作基准测试
 
-use rand::Rng;
 use gm_sdk::sm2::{sm2_sign, sm2_verify, sm2_generate_keypair};
 use std::time::Instant;
 
@@ -0,0 +1,103 @@
+// SM2密文格式测试
+
+use gm_sdk::sm2::{
+    Sm2CiphertextFormat, sm2_convert_ciphertext, sm2_decrypt, sm2_decrypt_with_format, sm2_encrypt,
+    sm2_encrypt_with_format, sm2_generate_keypair,
+};
+
+const FORMATS: [Sm2CiphertextFormat; 5] = [
+    Sm2CiphertextFormat::C1C3C2,
+    Sm2CiphertextFormat::C1C2C3,
+    Sm2CiphertextFormat::C1C3C2Raw,
+    Sm2CiphertextFormat::C1C2C3Raw,
+    Sm2CiphertextFormat::Der,
+];
+
+#[test]
+fn test_sm2_encrypt_decrypt_all_formats() {
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"encryption standard";
+
+    for format in FORMATS {
+        let ciphertext = sm2_encrypt_with_format(&public_key, message, format);
+        assert!(!ciphertext.is_empty());
+        let plaintext = sm2_decrypt_with_format(&private_key, &ciphertext, format).unwrap();
+        assert_eq!(plaintext, message, "{:?}", format);
+    }
+}
+
+#[test]
+fn test_sm2_ciphertext_layouts() {
+    let (_, public_key) = sm2_generate_keypair();
+    let message = [0xABu8; 40];
+    let c1c3c2 = sm2_encrypt(&public_key, &message);
+    let c1 = &c1c3c2[1..65];
+    let c3 = &c1c3c2[65..97];
+    let c2 = &c1c3c2[97..];
+
+    let c1c2c3 = sm2_convert_ciphertext(&c1c3c2, Sm2CiphertextFormat::C1C3C2, Sm2CiphertextFormat::C1C2C3).unwrap();
+    assert_eq!(c1c2c3, [&[0x04], c1, c2, c3].concat());
+
+    let raw = sm2_convert_ciphertext(&c1c3c2, Sm2CiphertextFormat::C1C3C2, Sm2CiphertextFormat::C1C3C2Raw).unwrap();
+    assert_eq!(raw, [c1, c3, c2].concat());
+
+    let raw = sm2_convert_ciphertext(&c1c3c2, Sm2CiphertextFormat::C1C3C2, Sm2CiphertextFormat::C1C2C3Raw).unwrap();
+    assert_eq!(raw, [c1, c2, c3].concat());
+}
+
+#[test]
+fn test_sm2_ciphertext_convert_round_trip() {
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"convert between every pair of formats";
+    let original = sm2_encrypt(&public_key, message);
+
+    for from in FORMATS {
+        let source = sm2_convert_ciphertext(&original, Sm2CiphertextFormat::C1C3C2, from).unwrap();
+        for to in FORMATS {
+            let converted = sm2_convert_ciphertext(&source, from, to).unwrap();
+            let back = sm2_convert_ciphertext(&converted, to, Sm2CiphertextFormat::C1C3C2).unwrap();
+            assert_eq!(back, original, "{:?} -> {:?}", from, to);
+        }
+    }
+    assert_eq!(sm2_decrypt(&private_key, &original).unwrap(), message);
+}
+
+#[test]
+fn test_sm2_ciphertext_interop() {
+    // RustCrypto sm2 能解密 C1C2C3 与 DER 格式
+    use sm2::pke::{DecryptingKey, Mode};
+
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"encryption standard";
+
+    let old_mode = sm2_encrypt_with_format(&public_key, message, Sm2CiphertextFormat::C1C2C3);
+    let secret = sm2::SecretKey::from_slice(&private_key).unwrap();
+    let decrypting_key = DecryptingKey::new_with_mode(secret.to_nonzero_scalar(), Mode::C1C2C3);
+    assert_eq!(decrypting_key.decrypt(&old_mode).unwrap(), message);
+
+    let der = sm2_encrypt_with_format(&public_key, message, Sm2CiphertextFormat::Der);
+    let decrypting_key = DecryptingKey::from_slice(&private_key).unwrap();
+    assert_eq!(decrypting_key.decrypt_der(&der).unwrap(), message);
+}
+
+#[test]
+fn test_sm2_ciphertext_rejects_malformed() {
+    let (_, public_key) = sm2_generate_keypair();
+    let ciphertext = sm2_encrypt_with_format(&public_key, b"abc", Sm2CiphertextFormat::Der);
+
+    // 尾随数据
+    let mut trailing = ciphertext.clone();
+    trailing.push(0);
+    assert!(sm2_convert_ciphertext(&trailing, Sm2CiphertextFormat::Der, Sm2CiphertextFormat::C1C3C2).is_none());
+
+    // 截断
+    assert!(
+        sm2_convert_ciphertext(&ciphertext[..ciphertext.len() - 1], Sm2CiphertextFormat::Der, Sm2CiphertextFormat::C1C3C2)
+            .is_none()
+    );
+
+    // C1前缀不是04
+    let mut prefixed = sm2_convert_ciphertext(&ciphertext, Sm2CiphertextFormat::Der, Sm2CiphertextFormat::C1C3C2).unwrap();
+    prefixed[0] = 0x02;
+    assert!(sm2_convert_ciphertext(&prefixed, Sm2CiphertextFormat::C1C3C2, Sm2CiphertextFormat::Der).is_none());
+}
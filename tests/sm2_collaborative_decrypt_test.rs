@@ -0,0 +1,63 @@
+// SM2两方协同解密测试
+
+use gm_sdk::{
+    Sm2CoDecryptRequest, Sm2CoDecryptResponse, Sm2CoSignClient, Sm2CoSignClientKeygen, Sm2CoSignServer,
+    sm2_encrypt,
+};
+
+fn keygen() -> (Sm2CoSignClient, Sm2CoSignServer) {
+    let (client_keygen, request) = Sm2CoSignClientKeygen::new();
+    let (server, response) = Sm2CoSignServer::keygen(&request).unwrap();
+    (client_keygen.finish(&response).unwrap(), server)
+}
+
+#[test]
+fn test_sm2_collaborative_decrypt() {
+    // 用联合公钥做普通SM2加密，双方协同解密得到相同明文
+    let (client, server) = keygen();
+    for message in [&b"a"[..], b"encryption standard", &[0x5Au8; 1000]] {
+        let ciphertext = sm2_encrypt(&client.public_key(), message);
+        let (session, request) = client.decrypt_start(&ciphertext).unwrap();
+        let request = Sm2CoDecryptRequest::from_bytes(&request.to_bytes());
+        let response = server.decrypt(&request).unwrap();
+        let response = Sm2CoDecryptResponse::from_bytes(&response.to_bytes());
+        assert_eq!(session.finish(&response).unwrap(), message);
+    }
+}
+
+#[test]
+fn test_sm2_collaborative_decrypt_wrong_share() {
+    // 另一服务端分量无法参与解密
+    let (client, _) = keygen();
+    let (_, other_server) = keygen();
+    let ciphertext = sm2_encrypt(&client.public_key(), b"secret");
+    let (session, request) = client.decrypt_start(&ciphertext).unwrap();
+    assert!(session.finish(&other_server.decrypt(&request).unwrap()).is_none());
+}
+
+#[test]
+fn test_sm2_collaborative_decrypt_tampered() {
+    let (client, server) = keygen();
+    let mut ciphertext = sm2_encrypt(&client.public_key(), b"secret");
+    let last = ciphertext.len() - 1;
+    ciphertext[last] ^= 1;
+    let (session, request) = client.decrypt_start(&ciphertext).unwrap();
+    assert!(session.finish(&server.decrypt(&request).unwrap()).is_none());
+
+    // 响应被篡改
+    let ciphertext = sm2_encrypt(&client.public_key(), b"secret");
+    let (session, request) = client.decrypt_start(&ciphertext).unwrap();
+    let mut response = server.decrypt(&request).unwrap();
+    response.t2[63] ^= 1;
+    assert!(session.finish(&response).is_none());
+}
+
+#[test]
+fn test_sm2_collaborative_decrypt_invalid_input() {
+    let (client, server) = keygen();
+    assert!(client.decrypt_start(&[0x04; 97]).is_none());
+    let mut ciphertext = sm2_encrypt(&client.public_key(), b"secret");
+    ciphertext[0] = 0x02;
+    assert!(client.decrypt_start(&ciphertext).is_none());
+    assert!(server.decrypt(&Sm2CoDecryptRequest { t1: [0u8; 64] }).is_none());
+}
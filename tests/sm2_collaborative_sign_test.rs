@@ -0,0 +1,86 @@
+// SM2两方协同签名测试
+
+use gm_sdk::{
+    SM2_DEFAULT_ID, Sm2CoSignClient, Sm2CoSignClientKeygen, Sm2CoSignKeygenRequest, Sm2CoSignKeygenResponse,
+    Sm2CoSignRequest, Sm2CoSignResponse, Sm2CoSignServer, sm2_message_digest, sm2_verify, sm2_verify_digest,
+    sm2_verify_with_id,
+};
+
+/// 在同一进程内完成密钥生成，消息经过字节编码往返模拟网络传输
+fn keygen() -> (Sm2CoSignClient, Sm2CoSignServer) {
+    let (client_keygen, request) = Sm2CoSignClientKeygen::new();
+    let request = Sm2CoSignKeygenRequest::from_bytes(&request.to_bytes());
+    let (server, response) = Sm2CoSignServer::keygen(&request).unwrap();
+    let response = Sm2CoSignKeygenResponse::from_bytes(&response.to_bytes());
+    let client = client_keygen.finish(&response).unwrap();
+    assert_eq!(client.public_key(), server.public_key());
+    (client, server)
+}
+
+#[test]
+fn test_sm2_collaborative_sign() {
+    let (client, server) = keygen();
+    let message = b"transfer 100 CNY";
+    let (session, request) = client.sign_start(message);
+    let request = Sm2CoSignRequest::from_bytes(&request.to_bytes());
+    let response = server.sign(&request).unwrap();
+    let response = Sm2CoSignResponse::from_bytes(&response.to_bytes());
+    let signature = session.finish(&response).unwrap();
+    assert!(sm2_verify(&client.public_key(), message, &signature));
+    assert!(!sm2_verify(&client.public_key(), b"transfer 999 CNY", &signature));
+}
+
+#[test]
+fn test_sm2_collaborative_sign_with_id_and_digest() {
+    let (client, server) = keygen();
+    let id = b"ALICE123@YAHOO.COM";
+    let (session, request) = client.sign_start_with_id(id, b"message digest").unwrap();
+    let signature = session.finish(&server.sign(&request).unwrap()).unwrap();
+    assert!(sm2_verify_with_id(&client.public_key(), id, b"message digest", &signature));
+
+    let digest = sm2_message_digest(&client.public_key(), SM2_DEFAULT_ID, b"document").unwrap();
+    let (session, request) = client.sign_digest_start(&digest);
+    assert_eq!(request.digest, digest);
+    let signature = session.finish(&server.sign(&request).unwrap()).unwrap();
+    assert!(sm2_verify_digest(&client.public_key(), &digest, &signature));
+}
+
+#[test]
+fn test_sm2_collaborative_restore_from_parts() {
+    // 分量持久化后恢复，仍能协同签名
+    let (client, server) = keygen();
+    let client = Sm2CoSignClient::from_parts(&client.share(), &client.public_key()).unwrap();
+    let server = Sm2CoSignServer::from_parts(&server.share(), &server.public_key()).unwrap();
+    let (session, request) = client.sign_start(b"hello");
+    let signature = session.finish(&server.sign(&request).unwrap()).unwrap();
+    assert!(sm2_verify(&client.public_key(), b"hello", &signature));
+
+    assert!(Sm2CoSignClient::from_parts(&[0u8; 32], &client.public_key()).is_none());
+    assert!(Sm2CoSignServer::from_parts(&server.share(), &[0u8; 64]).is_none());
+}
+
+#[test]
+fn test_sm2_collaborative_mismatched_shares() {
+    // 服务端分量不匹配或响应被篡改时客户端拒绝输出签名
+    let (client, _) = keygen();
+    let (_, other_server) = keygen();
+    let (session, request) = client.sign_start(b"hello");
+    assert!(session.finish(&other_server.sign(&request).unwrap()).is_none());
+
+    let (client, server) = keygen();
+    let (session, request) = client.sign_start(b"hello");
+    let mut response = server.sign(&request).unwrap();
+    response.s3[31] ^= 1;
+    assert!(session.finish(&response).is_none());
+}
+
+#[test]
+fn test_sm2_collaborative_invalid_messages() {
+    let (_, server) = keygen();
+    assert!(Sm2CoSignServer::keygen(&Sm2CoSignKeygenRequest { p1: [0u8; 64] }).is_none());
+    let request = Sm2CoSignRequest {
+        q1: [0u8; 64],
+        digest: [0u8; 32],
+    };
+    assert!(server.sign(&request).is_none());
+}
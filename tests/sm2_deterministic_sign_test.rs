@@ -0,0 +1,81 @@
+// SM2确定性签名测试
+
+use gm_sdk::{
+    SM2_DEFAULT_ID, Sm2NonceMode, sm2_derive_public_key, sm2_generate_keypair, sm2_sign_deterministic,
+    sm2_sign_with_nonce_mode, sm2_verify, sm2_verify_with_id,
+};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn private_key() -> [u8; 32] {
+    hex_to_bytes("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8")
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn test_sm2_deterministic_known_answer() {
+    // k由HMAC-SM3 DRBG派生，结果与独立实现一致
+    let private_key = private_key();
+    let public_key = sm2_derive_public_key(&private_key).unwrap();
+    let signature = sm2_sign_deterministic(&private_key, b"message digest");
+    let expected = hex_to_bytes(
+        "24858EE71D63E687FEEFE41F5AF80A59F0791EB1DABC2BBE71DAF0E57F06C367\
+         3D15550DE52785A435004C937256AC715C0E04176AC57062C6722FA692F7A491",
+    );
+    assert_eq!(signature.to_vec(), expected);
+    assert!(sm2_verify(&public_key, b"message digest", &signature));
+}
+
+#[test]
+fn test_sm2_deterministic_known_answer_with_id() {
+    let private_key = private_key();
+    let public_key = sm2_derive_public_key(&private_key).unwrap();
+    let id = b"ALICE123@YAHOO.COM";
+    let signature = sm2_sign_with_nonce_mode(&private_key, id, b"message digest", Sm2NonceMode::Deterministic);
+    let expected = hex_to_bytes(
+        "37D5572C900B5D1E6E98E64FA7462000B399746BEA1EEC13CD005DEA0B25364A\
+         13EFEDCDE44B6D37644BAF8294EBA2ECE597BB8FB9495D83570CBB3F739C7FC3",
+    );
+    assert_eq!(signature.to_vec(), expected);
+    assert!(sm2_verify_with_id(&public_key, id, b"message digest", &signature));
+}
+
+#[test]
+fn test_sm2_deterministic_reproducible() {
+    // 相同输入得到相同签名，不同消息得到不同签名
+    let (private_key, public_key) = sm2_generate_keypair();
+    let first = sm2_sign_deterministic(&private_key, b"hello");
+    let second = sm2_sign_deterministic(&private_key, b"hello");
+    let other = sm2_sign_deterministic(&private_key, b"world");
+    assert_eq!(first, second);
+    assert_ne!(first[..32], other[..32]);
+    assert!(sm2_verify(&public_key, b"hello", &first));
+    assert!(sm2_verify(&public_key, b"world", &other));
+}
+
+#[test]
+fn test_sm2_hedged_sign() {
+    // 混入新鲜随机数后每次签名不同，但都能通过验签
+    let (private_key, public_key) = sm2_generate_keypair();
+    let first = sm2_sign_with_nonce_mode(&private_key, SM2_DEFAULT_ID, b"hello", Sm2NonceMode::Hedged);
+    let second = sm2_sign_with_nonce_mode(&private_key, SM2_DEFAULT_ID, b"hello", Sm2NonceMode::Hedged);
+    assert_ne!(first, second);
+    assert!(sm2_verify(&public_key, b"hello", &first));
+    assert!(sm2_verify(&public_key, b"hello", &second));
+}
+
+#[test]
+fn test_sm2_deterministic_invalid_key() {
+    // 私钥无效时返回全零签名
+    assert_eq!(sm2_sign_deterministic(&[0u8; 32], b"hello"), [0u8; 64]);
+    assert_eq!(
+        sm2_sign_with_nonce_mode(&[0xFFu8; 32], SM2_DEFAULT_ID, b"hello", Sm2NonceMode::Hedged),
+        [0u8; 64]
+    );
+}
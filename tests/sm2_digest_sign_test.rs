@@ -0,0 +1,61 @@
+// SM2摘要签名验签测试
+
+use gm_sdk::{
+    SM2_DEFAULT_ID, Sm2NonceMode, sm2_derive_public_key, sm2_generate_keypair, sm2_message_digest,
+    sm2_sign, sm2_sign_deterministic, sm2_sign_digest, sm2_sign_digest_with_nonce_mode, sm2_verify,
+    sm2_verify_digest,
+};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_sm2_digest_matches_message_api() {
+    // 摘要签名与消息签名可以互相验证
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"a large document";
+    let digest = sm2_message_digest(&public_key, SM2_DEFAULT_ID, message).unwrap();
+
+    let signature = sm2_sign_digest(&private_key, &digest);
+    assert!(sm2_verify(&public_key, message, &signature));
+
+    let signature = sm2_sign(&private_key, message);
+    assert!(sm2_verify_digest(&public_key, &digest, &signature));
+}
+
+#[test]
+fn test_sm2_digest_deterministic() {
+    // 确定性模式下摘要签名与消息签名结果一致
+    let private_key: [u8; 32] = hex_to_bytes("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8")
+        .try_into()
+        .unwrap();
+    let public_key = sm2_derive_public_key(&private_key).unwrap();
+    let digest = sm2_message_digest(&public_key, SM2_DEFAULT_ID, b"message digest").unwrap();
+    assert_eq!(
+        sm2_sign_digest_with_nonce_mode(&private_key, &digest, Sm2NonceMode::Deterministic),
+        sm2_sign_deterministic(&private_key, b"message digest")
+    );
+}
+
+#[test]
+fn test_sm2_verify_digest_rejects_wrong_digest() {
+    let (private_key, public_key) = sm2_generate_keypair();
+    let digest = [0x5Au8; 32];
+    let signature = sm2_sign_digest(&private_key, &digest);
+    assert!(sm2_verify_digest(&public_key, &digest, &signature));
+
+    let mut other = digest;
+    other[31] ^= 1;
+    assert!(!sm2_verify_digest(&public_key, &other, &signature));
+}
+
+#[test]
+fn test_sm2_digest_invalid_input() {
+    assert_eq!(sm2_sign_digest(&[0u8; 32], &[1u8; 32]), [0u8; 64]);
+    assert!(!sm2_verify_digest(&[0u8; 64], &[1u8; 32], &[1u8; 64]));
+    assert!(sm2_message_digest(&[0u8; 64], SM2_DEFAULT_ID, b"hello").is_none());
+}
@@ -1,13 +1,106 @@
 // SM2加密解密测试
 
+use gm_sdk::sm2::{sm2_decrypt, sm2_encrypt, sm2_generate_keypair};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
 #[test]
 fn test_sm2_encrypt_decrypt() {
     // 测试加密解密功能
-    // TODO: 实现测试
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"encryption standard";
+
+    let ciphertext = sm2_encrypt(&public_key, message);
+    // C1(65) || C3(32) || C2
+    assert_eq!(ciphertext.len(), 65 + 32 + message.len());
+    assert_eq!(ciphertext[0], 0x04);
+
+    assert_eq!(sm2_decrypt(&private_key, &ciphertext).unwrap(), message);
+}
+
+#[test]
+fn test_sm2_encrypt_decrypt_long_message() {
+    // 超过255字节的消息不应被截断
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+
+    let ciphertext = sm2_encrypt(&public_key, &message);
+    assert_eq!(sm2_decrypt(&private_key, &ciphertext).unwrap(), message);
 }
 
 #[test]
 fn test_sm2_encrypt_decrypt_tampered_ciphertext() {
     // 测试篡改密文的解密失败
-    // TODO: 实现测试
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"encryption standard";
+    let ciphertext = sm2_encrypt(&public_key, message);
+
+    // 篡改C2
+    let mut tampered = ciphertext.clone();
+    *tampered.last_mut().unwrap() ^= 0x01;
+    assert!(sm2_decrypt(&private_key, &tampered).is_none());
+
+    // 篡改C3
+    let mut tampered = ciphertext.clone();
+    tampered[70] ^= 0x01;
+    assert!(sm2_decrypt(&private_key, &tampered).is_none());
+
+    // C1不在曲线上
+    let mut tampered = ciphertext.clone();
+    tampered[64] ^= 0x01;
+    assert!(sm2_decrypt(&private_key, &tampered).is_none());
+
+    // 截断
+    assert!(sm2_decrypt(&private_key, &ciphertext[..97]).is_none());
+
+    // 错误私钥
+    let (other_key, _) = sm2_generate_keypair();
+    assert!(sm2_decrypt(&other_key, &ciphertext).is_none());
+}
+
+#[test]
+fn test_sm2_decrypt_standard_vector() {
+    // GB/T 32918.5 示例：M = "encryption standard"
+    let private_key: [u8; 32] =
+        hex_to_bytes("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8")
+            .try_into()
+            .unwrap();
+    let ciphertext = hex_to_bytes(
+        "04\
+         04EBFC718E8D1798620432268E77FEB6415E2EDE0E073C0F4F640ECD2E149A73\
+         E858F9D81E5430A57B36DAAB8F950A3C64E6EE6A63094D99283AFF767E124DF0\
+         59983C18F809E262923C53AEC295D30383B54E39D609D160AFCB1908D0BD8766\
+         21886CA989CA9C7D58087307CA93092D651EFA",
+    );
+    assert_eq!(
+        sm2_decrypt(&private_key, &ciphertext).unwrap(),
+        b"encryption standard"
+    );
+}
+
+#[test]
+fn test_sm2_encrypt_interop() {
+    // RustCrypto sm2 能解密本实现生成的 C1C3C2 密文
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"encryption standard";
+    let ciphertext = sm2_encrypt(&public_key, message);
+
+    let decrypting_key = sm2::pke::DecryptingKey::from_slice(&private_key).unwrap();
+    assert_eq!(decrypting_key.decrypt(&ciphertext).unwrap(), message);
+}
+
+#[test]
+fn test_sm2_encrypt_invalid_input() {
+    // 无效公钥或空消息返回空密文
+    let (_, public_key) = sm2_generate_keypair();
+    assert!(sm2_encrypt(&public_key, b"").is_empty());
+
+    let mut bad_key = public_key;
+    bad_key[0] ^= 0x01;
+    assert!(sm2_encrypt(&bad_key, b"abc").is_empty());
 }
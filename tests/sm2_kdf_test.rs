@@ -0,0 +1,67 @@
+// SM2 KDF测试
+
+use gm_sdk::sm2::{SM2_KDF_MAX_LEN, Sm2KdfReader, sm2_kdf, sm2_kdf_into};
+use gm_sdk::sm3::sm3_hash;
+
+/// 按定义逐块计算 SM3(Z || ct || SharedInfo)
+fn reference_kdf(z: &[u8], shared_info: &[u8], klen: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut counter: u32 = 1;
+    while out.len() < klen {
+        let mut input = z.to_vec();
+        input.extend_from_slice(&counter.to_be_bytes());
+        input.extend_from_slice(shared_info);
+        out.extend_from_slice(&sm3_hash(&input));
+        counter += 1;
+    }
+    out.truncate(klen);
+    out
+}
+
+#[test]
+fn test_sm2_kdf_matches_definition() {
+    let z = b"shared secret x2 || y2";
+    for klen in [0, 1, 31, 32, 33, 64, 100, 1000] {
+        assert_eq!(sm2_kdf(z, klen).unwrap(), reference_kdf(z, b"", klen));
+    }
+}
+
+#[test]
+fn test_sm2_kdf_shared_info() {
+    let z = b"shared secret";
+    let info = b"vendor shared info";
+    let mut out = [0u8; 80];
+    assert!(sm2_kdf_into(z, info, &mut out));
+    assert_eq!(out.to_vec(), reference_kdf(z, info, 80));
+
+    // 空SharedInfo与标准KDF一致
+    assert!(sm2_kdf_into(z, b"", &mut out));
+    assert_eq!(out.to_vec(), sm2_kdf(z, 80).unwrap());
+}
+
+#[test]
+fn test_sm2_kdf_reader_streaming() {
+    let z = b"keystream seed";
+    let expected = sm2_kdf(z, 500).unwrap();
+
+    // 以不同大小分段读取，结果与一次性派生一致
+    let mut reader = Sm2KdfReader::new(z, b"");
+    let mut streamed = Vec::new();
+    for chunk in [1, 7, 32, 33, 0, 100, 327] {
+        let mut buf = vec![0u8; chunk];
+        assert!(reader.read(&mut buf));
+        streamed.extend_from_slice(&buf);
+    }
+    assert_eq!(streamed, expected);
+}
+
+#[test]
+fn test_sm2_kdf_length_limit() {
+    let reader = Sm2KdfReader::new(b"z", b"");
+    assert_eq!(reader.remaining(), SM2_KDF_MAX_LEN);
+    assert_eq!(SM2_KDF_MAX_LEN, ((1u64 << 32) - 1) * 32);
+
+    if let Ok(klen) = usize::try_from(SM2_KDF_MAX_LEN + 1) {
+        assert!(sm2_kdf(b"z", klen).is_none());
+    }
+}
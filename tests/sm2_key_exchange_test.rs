@@ -0,0 +1,114 @@
+// SM2密钥交换测试
+
+use gm_sdk::sm2::{Sm2ExchangeRole, Sm2KeyExchange, sm2_derive_public_key, sm2_generate_keypair};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn hex32(hex: &str) -> [u8; 32] {
+    hex_to_bytes(hex).try_into().unwrap()
+}
+
+fn hex64(hex: &str) -> [u8; 64] {
+    hex_to_bytes(hex).try_into().unwrap()
+}
+
+#[test]
+fn test_sm2_key_exchange_standard_vector() {
+    // GM/T 0003.5 推荐曲线密钥交换示例
+    let id = b"1234567812345678";
+    let d_a = hex32("81EB26E941BB5AF16DF116495F90695272AE2CD63D6C4AE1678418BE48230029");
+    let p_a = hex64(
+        "160E12897DF4EDB61DD812FEB96748FBD3CCF4FFE26AA6F6DB9540AF49C94232\
+         4A7DAD08BB9A459531694BEB20AA489D6649975E1BFCF8C4741B78B4B223007F",
+    );
+    let d_b = hex32("785129917D45A9EA5437A59356B82338EAADDA6CEB199088F14AE10DEFA229B5");
+    let p_b = hex64(
+        "6AE848C57C53C7B1B5FA99EB2286AF078BA64C64591B8B566F7357D576F16DFB\
+         EE489D771621A27B36C5C7992062E9CD09A9264386F3FBEA54DFF69305621C4D",
+    );
+    let r_a = hex32("D4DE15474DB74D06491C440D305E012400990F3E390C7E87153C12DB2EA60BB3");
+    let r_b = hex32("7E07124814B309489125EAED101113164EBF0F3458C5BD88335C1F9D596243D6");
+    assert_eq!(sm2_derive_public_key(&d_a), Some(p_a));
+    assert_eq!(sm2_derive_public_key(&d_b), Some(p_b));
+
+    let alice = Sm2KeyExchange::with_ephemeral_key(Sm2ExchangeRole::Initiator, &d_a, &p_a, id, &p_b, id, &r_a).unwrap();
+    let bob = Sm2KeyExchange::with_ephemeral_key(Sm2ExchangeRole::Responder, &d_b, &p_b, id, &p_a, id, &r_b).unwrap();
+
+    let bob_result = bob.compute(&alice.ephemeral_public_key(), 16).unwrap();
+    let alice_result = alice.compute(&bob.ephemeral_public_key(), 16).unwrap();
+
+    let expected = hex_to_bytes("6C89347354DE2484C60B4AB1FDE4C6E5");
+    assert_eq!(alice_result.key, expected);
+    assert_eq!(bob_result.key, expected);
+    assert!(alice_result.verify_peer_confirmation(&bob_result.confirmation));
+    assert!(bob_result.verify_peer_confirmation(&alice_result.confirmation));
+}
+
+#[test]
+fn test_sm2_key_exchange_random_keys() {
+    let (d_a, p_a) = sm2_generate_keypair();
+    let (d_b, p_b) = sm2_generate_keypair();
+    let id_a = b"ALICE123@YAHOO.COM";
+    let id_b = b"BILL456@YAHOO.COM";
+
+    let alice = Sm2KeyExchange::with_ids(Sm2ExchangeRole::Initiator, &d_a, &p_a, id_a, &p_b, id_b).unwrap();
+    let bob = Sm2KeyExchange::with_ids(Sm2ExchangeRole::Responder, &d_b, &p_b, id_b, &p_a, id_a).unwrap();
+
+    // 任意长度的共享密钥
+    for klen in [1, 16, 32, 33, 100] {
+        let a = alice.compute(&bob.ephemeral_public_key(), klen).unwrap();
+        let b = bob.compute(&alice.ephemeral_public_key(), klen).unwrap();
+        assert_eq!(a.key.len(), klen);
+        assert_eq!(a.key, b.key);
+        assert!(a.verify_peer_confirmation(&b.confirmation));
+        assert!(b.verify_peer_confirmation(&a.confirmation));
+        assert_ne!(a.confirmation, b.confirmation);
+    }
+}
+
+#[test]
+fn test_sm2_key_exchange_mismatch() {
+    let (d_a, p_a) = sm2_generate_keypair();
+    let (d_b, p_b) = sm2_generate_keypair();
+
+    // 双方使用不同的ID时密钥与确认值均不一致
+    let alice = Sm2KeyExchange::new(Sm2ExchangeRole::Initiator, &d_a, &p_a, &p_b).unwrap();
+    let bob = Sm2KeyExchange::with_ids(Sm2ExchangeRole::Responder, &d_b, &p_b, b"1234567812345678", &p_a, b"other").unwrap();
+    let a = alice.compute(&bob.ephemeral_public_key(), 16).unwrap();
+    let b = bob.compute(&alice.ephemeral_public_key(), 16).unwrap();
+    assert_ne!(a.key, b.key);
+    assert!(!a.verify_peer_confirmation(&b.confirmation));
+    assert!(!b.verify_peer_confirmation(&a.confirmation));
+}
+
+#[test]
+fn test_sm2_key_exchange_rejects_invalid_input() {
+    let (d_a, p_a) = sm2_generate_keypair();
+    let (_, p_b) = sm2_generate_keypair();
+
+    // 公私钥不匹配
+    assert!(Sm2KeyExchange::new(Sm2ExchangeRole::Initiator, &d_a, &p_b, &p_a).is_none());
+
+    // 对方公钥不在曲线上
+    let mut bad = p_b;
+    bad[63] ^= 0x01;
+    assert!(Sm2KeyExchange::new(Sm2ExchangeRole::Initiator, &d_a, &p_a, &bad).is_none());
+
+    // 对方临时公钥不在曲线上
+    let alice = Sm2KeyExchange::new(Sm2ExchangeRole::Initiator, &d_a, &p_a, &p_b).unwrap();
+    assert!(alice.compute(&bad, 16).is_none());
+}
+
+#[test]
+fn test_sm2_key_exchange_rejects_long_id() {
+    let (d_a, p_a) = sm2_generate_keypair();
+    let (_, p_b) = sm2_generate_keypair();
+    let long_id = vec![0x41; gm_sdk::sm2::SM2_MAX_ID_LEN + 1];
+    assert!(Sm2KeyExchange::with_ids(Sm2ExchangeRole::Initiator, &d_a, &p_a, &long_id, &p_b, b"bob").is_none());
+    assert!(Sm2KeyExchange::with_ids(Sm2ExchangeRole::Initiator, &d_a, &p_a, b"alice", &p_b, &long_id).is_none());
+}
@@ -0,0 +1,87 @@
+// SM2密钥校验测试
+
+use gm_sdk::sm2::{SM2_GX, SM2_GY, SM2_N, SM2_P};
+use gm_sdk::{
+    Sm2PublicKey, sm2_encrypt, sm2_generate_keypair, sm2_sign, sm2_validate_keypair, sm2_validate_private_key,
+    sm2_validate_public_key, sm2_verify,
+};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_sm2_validate_generated_keys() {
+    for _ in 0..8 {
+        let (private_key, public_key) = sm2_generate_keypair();
+        assert!(sm2_validate_private_key(&private_key));
+        assert!(sm2_validate_public_key(&public_key));
+        assert!(sm2_validate_keypair(&private_key, &public_key));
+    }
+}
+
+#[test]
+fn test_sm2_validate_private_key_range() {
+    // 合法范围为 [1, n-2]
+    let mut n_minus_1 = SM2_N;
+    n_minus_1[31] -= 1;
+    let mut n_minus_2 = SM2_N;
+    n_minus_2[31] -= 2;
+    let mut one = [0u8; 32];
+    one[31] = 1;
+
+    assert!(sm2_validate_private_key(&one));
+    assert!(sm2_validate_private_key(&n_minus_2));
+    assert!(!sm2_validate_private_key(&[0u8; 32]));
+    assert!(!sm2_validate_private_key(&n_minus_1));
+    assert!(!sm2_validate_private_key(&SM2_N));
+    assert!(!sm2_validate_private_key(&[0xFFu8; 32]));
+}
+
+#[test]
+fn test_sm2_validate_public_key_rejects_invalid() {
+    let mut generator = [0u8; 64];
+    generator[..32].copy_from_slice(&SM2_GX);
+    generator[32..].copy_from_slice(&SM2_GY);
+    assert!(sm2_validate_public_key(&generator));
+
+    // 无穷远点无法用 x || y 表示，全零编码不在曲线上
+    assert!(!sm2_validate_public_key(&[0u8; 64]));
+
+    // 不在曲线上（无效曲线攻击）
+    let mut off_curve = generator;
+    off_curve[63] ^= 1;
+    assert!(!sm2_validate_public_key(&off_curve));
+
+    // 坐标不小于p：x + p 与 x 同余但编码越界
+    let mut out_of_range = generator;
+    out_of_range[..32].copy_from_slice(&SM2_P);
+    assert!(!sm2_validate_public_key(&out_of_range));
+}
+
+#[test]
+fn test_sm2_validate_keypair_mismatch() {
+    let (private_key, _) = sm2_generate_keypair();
+    let (_, other_public_key) = sm2_generate_keypair();
+    assert!(!sm2_validate_keypair(&private_key, &other_public_key));
+    assert!(!sm2_validate_keypair(&[0u8; 32], &other_public_key));
+}
+
+#[test]
+fn test_sm2_invalid_public_key_rejected_on_import() {
+    // 无效公钥在标量乘之前即被拒绝
+    let private_key: [u8; 32] = hex_to_bytes("3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8")
+        .try_into()
+        .unwrap();
+    let signature = sm2_sign(&private_key, b"hello");
+    let mut off_curve = [0u8; 64];
+    off_curve[..32].copy_from_slice(&SM2_GX);
+    off_curve[32..].copy_from_slice(&SM2_GX);
+
+    assert!(!sm2_verify(&off_curve, b"hello", &signature));
+    assert!(sm2_encrypt(&off_curve, b"hello").is_empty());
+    assert!(Sm2PublicKey::from_bytes(&off_curve).is_none());
+}
@@ -1,25 +0,0 @@
-// SM2椭圆曲线点运算测试
-
-#[test]
-fn test_point_add() {
-    // 测试点加功能
-    // TODO: 实现测试
-}
-
-#[test]
-fn test_point_double() {
-    // 测试点倍功能
-    // TODO: 实现测试
-}
-
-#[test]
-fn test_point_mul() {
-    // 测试点乘功能
-    // TODO: 实现测试
-}
-
-#[test]
-fn test_point_mul_g() {
-    // 测试点乘生成元G
-    // TODO: 实现测试
-}
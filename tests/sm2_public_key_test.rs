@@ -0,0 +1,101 @@
+// SM2公钥点编码测试
+
+use gm_sdk::{Sm2PointFormat, Sm2PublicKey, sm2_generate_keypair};
+use gm_sdk::sm2::{SM2_GX, SM2_GY, SM2_P};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn generator_bytes() -> [u8; 64] {
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&SM2_GX);
+    bytes[32..].copy_from_slice(&SM2_GY);
+    bytes
+}
+
+#[test]
+fn test_sm2_public_key_generator_encodings() {
+    // G的y为偶数：压缩前缀02，混合前缀06
+    let key = Sm2PublicKey::from_bytes(&generator_bytes()).unwrap();
+    let compressed = key.to_sec1_bytes(Sm2PointFormat::Compressed);
+    assert_eq!(
+        compressed,
+        hex_to_bytes("0232C4AE2C1F1981195F9904466A39C9948FE30BBFF2660BE1715A4589334C74C7")
+    );
+    let uncompressed = key.to_sec1_bytes(Sm2PointFormat::Uncompressed);
+    assert_eq!(uncompressed[0], 0x04);
+    assert_eq!(&uncompressed[1..], &generator_bytes()[..]);
+    let hybrid = key.to_sec1_bytes(Sm2PointFormat::Hybrid);
+    assert_eq!(hybrid[0], 0x06);
+    assert_eq!(&hybrid[1..], &generator_bytes()[..]);
+
+    for encoded in [compressed, uncompressed, hybrid] {
+        assert_eq!(Sm2PublicKey::from_sec1_bytes(&encoded), Some(key));
+    }
+}
+
+#[test]
+fn test_sm2_public_key_roundtrip() {
+    // 随机公钥在三种格式之间往返，并与RustCrypto sm2互通
+    for _ in 0..16 {
+        let (private_key, public_key) = sm2_generate_keypair();
+        let key = Sm2PublicKey::from_private_key(&private_key).unwrap();
+        assert_eq!(key.to_bytes(), public_key);
+
+        for format in [Sm2PointFormat::Uncompressed, Sm2PointFormat::Compressed, Sm2PointFormat::Hybrid] {
+            let encoded = key.to_sec1_bytes(format);
+            assert_eq!(Sm2PublicKey::from_sec1_bytes(&encoded), Some(key));
+        }
+
+        let compressed = key.to_sec1_bytes(Sm2PointFormat::Compressed);
+        let other = sm2::PublicKey::from_sec1_bytes(&compressed).unwrap();
+        assert_eq!(&other.to_sec1_bytes()[1..], &public_key[..]);
+    }
+}
+
+#[test]
+fn test_sm2_public_key_rejects_invalid() {
+    let key = Sm2PublicKey::from_bytes(&generator_bytes()).unwrap();
+
+    // y的奇偶与前缀不符
+    let mut hybrid = key.to_sec1_bytes(Sm2PointFormat::Hybrid);
+    hybrid[0] = 0x07;
+    assert!(Sm2PublicKey::from_sec1_bytes(&hybrid).is_none());
+
+    // 不在曲线上
+    let mut uncompressed = key.to_sec1_bytes(Sm2PointFormat::Uncompressed);
+    uncompressed[64] ^= 1;
+    assert!(Sm2PublicKey::from_sec1_bytes(&uncompressed).is_none());
+
+    // x不小于p
+    let mut compressed = vec![0x02];
+    compressed.extend_from_slice(&SM2_P);
+    assert!(Sm2PublicKey::from_sec1_bytes(&compressed).is_none());
+
+    // 长度或前缀错误、无穷远点
+    let compressed = key.to_sec1_bytes(Sm2PointFormat::Compressed);
+    assert!(Sm2PublicKey::from_sec1_bytes(&compressed[..32]).is_none());
+    let mut wrong_tag = compressed.clone();
+    wrong_tag[0] = 0x04;
+    assert!(Sm2PublicKey::from_sec1_bytes(&wrong_tag).is_none());
+    assert!(Sm2PublicKey::from_sec1_bytes(&[0x00]).is_none());
+    assert!(Sm2PublicKey::from_sec1_bytes(&[]).is_none());
+}
+
+#[test]
+fn test_sm2_public_key_rejects_non_residue() {
+    // 找一个 x³ + ax + b 不是二次剩余的x，压缩编码无法解压
+    let mut rejected = 0;
+    for i in 0u8..16 {
+        let mut compressed = vec![0x02];
+        compressed.extend_from_slice(&[i; 32]);
+        if Sm2PublicKey::from_sec1_bytes(&compressed).is_none() {
+            rejected += 1;
+        }
+    }
+    assert!(rejected > 0);
+}
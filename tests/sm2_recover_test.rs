@@ -0,0 +1,62 @@
+// SM2公钥恢复测试
+
+use gm_sdk::{
+    SM2_DEFAULT_ID, Sm2BatchVerifier, sm2_generate_keypair, sm2_message_digest, sm2_recover_public_key,
+    sm2_sign_digest_recoverable, sm2_verify_digest,
+};
+
+#[test]
+fn test_sm2_recover_public_key() {
+    for i in 0..16 {
+        let (private_key, public_key) = sm2_generate_keypair();
+        let message = format!("transaction {}", i);
+        let digest = sm2_message_digest(&public_key, SM2_DEFAULT_ID, message.as_bytes()).unwrap();
+        let (signature, recovery_id) = sm2_sign_digest_recoverable(&private_key, &digest);
+        assert!(recovery_id < 4);
+        assert!(sm2_verify_digest(&public_key, &digest, &signature));
+        assert_eq!(sm2_recover_public_key(&digest, &signature, recovery_id), Some(public_key));
+
+        // 其他恢复标识得不到该公钥
+        for other in (0..4).filter(|&id| id != recovery_id) {
+            assert_ne!(sm2_recover_public_key(&digest, &signature, other), Some(public_key));
+        }
+    }
+}
+
+#[test]
+fn test_sm2_recover_wrong_digest() {
+    // 摘要不符时恢复出的公钥不同，且不能通过原公钥验签
+    let (private_key, public_key) = sm2_generate_keypair();
+    let digest = [0x11u8; 32];
+    let (signature, recovery_id) = sm2_sign_digest_recoverable(&private_key, &digest);
+    let other_digest = [0x22u8; 32];
+    let recovered = sm2_recover_public_key(&other_digest, &signature, recovery_id);
+    assert_ne!(recovered, Some(public_key));
+    if let Some(recovered) = recovered {
+        assert!(sm2_verify_digest(&recovered, &other_digest, &signature));
+    }
+}
+
+#[test]
+fn test_sm2_recover_invalid_input() {
+    let (private_key, _) = sm2_generate_keypair();
+    let digest = [0x33u8; 32];
+    let (signature, recovery_id) = sm2_sign_digest_recoverable(&private_key, &digest);
+    assert!(sm2_recover_public_key(&digest, &signature, 4).is_none());
+    assert!(sm2_recover_public_key(&digest, &[0u8; 64], recovery_id).is_none());
+    assert!(sm2_recover_public_key(&digest, &[0xFFu8; 64], recovery_id).is_none());
+    assert_eq!(sm2_sign_digest_recoverable(&[0u8; 32], &digest), ([0u8; 64], 0));
+}
+
+#[test]
+fn test_sm2_recoverable_batch_verify() {
+    // 恢复标识可直接用于批量验签
+    let mut batch = Sm2BatchVerifier::new();
+    for i in 0..8u8 {
+        let (private_key, public_key) = sm2_generate_keypair();
+        let digest = [i; 32];
+        let (signature, recovery_id) = sm2_sign_digest_recoverable(&private_key, &digest);
+        batch.push_with_recovery_id(&public_key, &digest, &signature, recovery_id);
+    }
+    assert_eq!(batch.verify(), Ok(()));
+}
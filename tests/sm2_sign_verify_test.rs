@@ -36,3 +36,125 @@ fn test_sm2_sign_verify_tampered_signature() {
     let result = sm2_verify(&public_key, message, &signature);
     assert!(!result);
 }
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_sm2_verify_standard_vector() {
+    // GB/T 32918.5 示例：ID = 1234567812345678，M = "message digest"
+    let public_key: [u8; 64] = hex_to_bytes(
+        "09F9DF311E5421A150DD7D161E4BC5C672179FAD1833FC076BB08FF356F35020\
+         CCEA490CE26775A52DC6EA718CC1AA600AED05FBF35E084A6632F6072DA9AD13",
+    )
+    .try_into()
+    .unwrap();
+    let signature: [u8; 64] = hex_to_bytes(
+        "F5A03B0648D2C4630EEAC513E1BB81A15944DA3827D5B74143AC7EACEEE720B3\
+         B1B6AA29DF212FD8763182BC0D421CA1BB9038FD1F7F42D4840B69C485BBC1AA",
+    )
+    .try_into()
+    .unwrap();
+    assert!(sm2_verify(&public_key, b"message digest", &signature));
+    assert!(!sm2_verify(&public_key, b"message digesT", &signature));
+}
+
+#[test]
+fn test_sm2_sign_verify_interop() {
+    // 与RustCrypto sm2互相验证签名
+    use sm2::dsa::signature::{Signer, Verifier};
+    use sm2::dsa::{Signature, SigningKey, VerifyingKey};
+
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"Hello SM2 Signature";
+    let distid = "1234567812345678";
+
+    let signature = sm2_sign(&private_key, message);
+    let mut sec1 = vec![0x04];
+    sec1.extend_from_slice(&public_key);
+    let verifying_key = VerifyingKey::from_sec1_bytes(distid, &sec1).unwrap();
+    let parsed = Signature::from_slice(&signature).unwrap();
+    assert!(verifying_key.verify(message, &parsed).is_ok());
+
+    let signing_key = SigningKey::from_slice(distid, &private_key).unwrap();
+    let other: Signature = signing_key.sign(message);
+    let other_bytes: [u8; 64] = other.to_bytes().into();
+    assert!(sm2_verify(&public_key, message, &other_bytes));
+}
+
+#[test]
+fn test_sm2_verify_rejects_out_of_range() {
+    // r、s须在 [1, n-1] 范围内，公钥须在曲线上
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"Hello SM2 Signature";
+    let signature = sm2_sign(&private_key, message);
+
+    let mut zero_r = signature;
+    zero_r[..32].fill(0);
+    assert!(!sm2_verify(&public_key, message, &zero_r));
+
+    let mut big_s = signature;
+    big_s[32..].fill(0xFF);
+    assert!(!sm2_verify(&public_key, message, &big_s));
+
+    let mut bad_key = public_key;
+    bad_key[63] ^= 0x01;
+    assert!(!sm2_verify(&bad_key, message, &signature));
+}
+
+#[test]
+fn test_sm2_sign_invalid_private_key() {
+    // 无效私钥返回全零签名
+    assert_eq!(sm2_sign(&[0u8; 32], b"abc"), [0u8; 64]);
+}
+
+#[test]
+fn test_sm2_sign_verify_with_id() {
+    // 使用非默认ID签名，只有相同ID才能验签通过
+    use gm_sdk::sm2::{SM2_MAX_ID_LEN, sm2_sign_with_id, sm2_verify_with_id};
+
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"Hello SM2 Signature";
+    let id = b"ALICE123@YAHOO.COM";
+
+    let signature = sm2_sign_with_id(&private_key, id, message);
+    assert!(sm2_verify_with_id(&public_key, id, message, &signature));
+    assert!(!sm2_verify_with_id(&public_key, b"BILL456@YAHOO.COM", message, &signature));
+    assert!(!sm2_verify(&public_key, message, &signature));
+
+    // 默认ID的签名等价于显式传入默认ID
+    let signature = sm2_sign(&private_key, message);
+    assert!(sm2_verify_with_id(&public_key, gm_sdk::sm2::SM2_DEFAULT_ID, message, &signature));
+
+    // 超长ID
+    let long_id = vec![0u8; SM2_MAX_ID_LEN + 1];
+    assert_eq!(sm2_sign_with_id(&private_key, &long_id, message), [0u8; 64]);
+    assert!(!sm2_verify_with_id(&public_key, &long_id, message, &signature));
+}
+
+#[test]
+fn test_sm2_sign_with_id_interop() {
+    // 与RustCrypto sm2使用相同的自定义ID互相验证
+    use gm_sdk::sm2::{sm2_sign_with_id, sm2_verify_with_id};
+    use sm2::dsa::signature::{Signer, Verifier};
+    use sm2::dsa::{Signature, SigningKey, VerifyingKey};
+
+    let (private_key, public_key) = sm2_generate_keypair();
+    let message = b"Hello SM2 Signature";
+    let distid = "ALICE123@YAHOO.COM";
+
+    let signature = sm2_sign_with_id(&private_key, distid.as_bytes(), message);
+    let mut sec1 = vec![0x04];
+    sec1.extend_from_slice(&public_key);
+    let verifying_key = VerifyingKey::from_sec1_bytes(distid, &sec1).unwrap();
+    assert!(verifying_key.verify(message, &Signature::from_slice(&signature).unwrap()).is_ok());
+
+    let signing_key = SigningKey::from_slice(distid, &private_key).unwrap();
+    let other: Signature = signing_key.sign(message);
+    let other_bytes: [u8; 64] = other.to_bytes().into();
+    assert!(sm2_verify_with_id(&public_key, distid.as_bytes(), message, &other_bytes));
+}
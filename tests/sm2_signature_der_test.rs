@@ -0,0 +1,110 @@
+// SM2签名DER编码测试
+
+use gm_sdk::sm2::SM2_N;
+use gm_sdk::{Sm2Signature, sm2_generate_keypair, sm2_sign, sm2_signature_from_der, sm2_signature_to_der, sm2_verify};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn raw_signature(hex: &str) -> [u8; 64] {
+    hex_to_bytes(hex).try_into().unwrap()
+}
+
+const R_HEX: &str = "24858EE71D63E687FEEFE41F5AF80A59F0791EB1DABC2BBE71DAF0E57F06C367";
+const S_HEX: &str = "3D15550DE52785A435004C937256AC715C0E04176AC57062C6722FA692F7A491";
+
+#[test]
+fn test_sm2_signature_der_known_answer() {
+    let raw = raw_signature(&format!("{}{}", R_HEX, S_HEX));
+    let der = sm2_signature_to_der(&raw).unwrap();
+    assert_eq!(der, hex_to_bytes(&format!("30440220{}0220{}", R_HEX, S_HEX)));
+    assert_eq!(sm2_signature_from_der(&der), Some(raw));
+
+    let signature = Sm2Signature::from_der(&der).unwrap();
+    assert_eq!(signature.to_bytes(), raw);
+    assert_eq!(signature.to_der(), der);
+}
+
+#[test]
+fn test_sm2_signature_der_minimal_integers() {
+    // r = 1：去掉前导零；s最高位为1：补0x00
+    let mut raw = [0u8; 64];
+    raw[31] = 1;
+    raw[32] = 0x80;
+    let der = sm2_signature_to_der(&raw).unwrap();
+    let mut expected = hex_to_bytes("3026020101022100");
+    expected.extend_from_slice(&raw[32..]);
+    assert_eq!(der, expected);
+    assert_eq!(sm2_signature_from_der(&der), Some(raw));
+}
+
+#[test]
+fn test_sm2_signature_der_round_trip() {
+    let (private_key, public_key) = sm2_generate_keypair();
+    for i in 0..16u8 {
+        let message = [i; 20];
+        let raw = sm2_sign(&private_key, &message);
+        let der = sm2_signature_to_der(&raw).unwrap();
+        let decoded = sm2_signature_from_der(&der).unwrap();
+        assert_eq!(decoded, raw);
+        assert!(sm2_verify(&public_key, &message, &decoded));
+    }
+}
+
+#[test]
+fn test_sm2_signature_der_rejects_out_of_range() {
+    // r = 0
+    let mut raw = raw_signature(&format!("{}{}", R_HEX, S_HEX));
+    raw[..32].copy_from_slice(&[0u8; 32]);
+    assert!(sm2_signature_to_der(&raw).is_none());
+    assert!(sm2_signature_from_der(&hex_to_bytes(&format!("30250201000220{}", S_HEX))).is_none());
+
+    // s = n
+    let mut der = hex_to_bytes(&format!("30450220{}022100", R_HEX));
+    der.extend_from_slice(&SM2_N);
+    assert!(sm2_signature_from_der(&der).is_none());
+
+    // r 超过32字节
+    let der = hex_to_bytes(&format!("3045022101{}0220{}", R_HEX, S_HEX));
+    assert!(sm2_signature_from_der(&der).is_none());
+}
+
+#[test]
+fn test_sm2_signature_der_rejects_malformed() {
+    let valid = hex_to_bytes(&format!("30440220{}0220{}", R_HEX, S_HEX));
+    assert!(sm2_signature_from_der(&valid).is_some());
+
+    // 尾随数据
+    let mut trailing = valid.clone();
+    trailing.push(0);
+    assert!(sm2_signature_from_der(&trailing).is_none());
+
+    // SEQUENCE内多余元素
+    let extra = hex_to_bytes(&format!("30470220{}0220{}020101", R_HEX, S_HEX));
+    assert!(sm2_signature_from_der(&extra).is_none());
+
+    // 非最短整数：多余的前导零
+    let padded = hex_to_bytes(&format!("3045022100{}0220{}", R_HEX, S_HEX));
+    assert!(sm2_signature_from_der(&padded).is_none());
+
+    // 负数
+    let mut negative = valid.clone();
+    negative[4] |= 0x80;
+    assert!(sm2_signature_from_der(&negative).is_none());
+
+    // 非最短长度编码
+    let long_length = hex_to_bytes(&format!("3081440220{}0220{}", R_HEX, S_HEX));
+    assert!(sm2_signature_from_der(&long_length).is_none());
+
+    // 截断、错误tag、空整数
+    assert!(sm2_signature_from_der(&valid[..valid.len() - 1]).is_none());
+    let mut wrong_tag = valid.clone();
+    wrong_tag[0] = 0x31;
+    assert!(sm2_signature_from_der(&wrong_tag).is_none());
+    assert!(sm2_signature_from_der(&hex_to_bytes(&format!("302402000220{}", S_HEX))).is_none());
+    assert!(sm2_signature_from_der(&[]).is_none());
+}
@@ -1,6 +1,6 @@
 // SM2标准测试
 
-use gm_sdk::sm2::{sm2_generate_keypair, sm2_sign, sm2_verify, sm2_encrypt, sm2_decrypt};
+use gm_sdk::sm2::{sm2_generate_keypair, sm2_derive_public_key, sm2_sign, sm2_verify, sm2_encrypt, sm2_decrypt};
 
 #[test]
 fn test_sm2_sign_verify_standard() {
@@ -12,12 +12,8 @@ fn test_sm2_sign_verify_standard() {
         0x13, 0x0a, 0x8a, 0x5d, 0x00, 0x4c, 0x78, 0x9e
     ];
     
-    // 生成对应的公钥（基于Rust的简化实现）
-    let mut public_key = [0u8; 64];
-    for i in 0..32 {
-        public_key[i] = private_key[i] ^ 0x55;
-        public_key[i + 32] = private_key[i] ^ 0xAA;
-    }
+    // 计算对应的公钥 P = [d]G
+    let public_key = sm2_derive_public_key(&private_key).unwrap();
     
     let message = [
         0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20,
@@ -44,12 +40,8 @@ fn test_sm2_encrypt_decrypt_standard() {
         0x43, 0xbd, 0xe2, 0xae, 0x70, 0xff, 0x96, 0x89
     ];
     
-    // 生成对应的公钥（基于Rust的简化实现）
-    let mut public_key = [0u8; 64];
-    for i in 0..32 {
-        public_key[i] = private_key[i] ^ 0x55;
-        public_key[i + 32] = private_key[i] ^ 0xAA;
-    }
+    // 计算对应的公钥 P = [d]G
+    let public_key = sm2_derive_public_key(&private_key).unwrap();
     
     let message = [
         0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88,
@@ -1,6 +1,6 @@
 // SM2测试
 
-use gm_sdk::sm2::{sm2_generate_keypair, sm2_sign, sm2_verify, sm2_encrypt, sm2_decrypt};
+use gm_sdk::sm2::{sm2_generate_keypair, sm2_derive_public_key, sm2_sign, sm2_verify, sm2_encrypt, sm2_decrypt};
 
 #[test]
 fn test_sm2_key_generation() {
@@ -15,6 +15,40 @@ fn test_sm2_key_generation() {
     assert_eq!(public_key.len(), 64);
 }
 
+#[test]
+fn test_sm2_key_generation_interop() {
+    // 生成的密钥对应能被其他SM2实现（RustCrypto sm2）接受，且公钥 = [d]G
+    for _ in 0..8 {
+        let (private_key, public_key) = sm2_generate_keypair();
+        assert_eq!(sm2_derive_public_key(&private_key), Some(public_key));
+
+        let secret = sm2::SecretKey::from_slice(&private_key).unwrap();
+        let encoded = secret.public_key().to_sec1_bytes();
+        assert_eq!(encoded[0], 0x04);
+        assert_eq!(&encoded[1..], &public_key[..]);
+
+        let mut sec1 = vec![0x04];
+        sec1.extend_from_slice(&public_key);
+        assert!(sm2::PublicKey::from_sec1_bytes(&sec1).is_ok());
+    }
+}
+
+#[test]
+fn test_sm2_derive_public_key_range() {
+    // 私钥须在 [1, n-2] 范围内
+    let n: [u8; 32] = gm_sdk::sm2::SM2_N;
+    let mut n_minus_1 = n;
+    n_minus_1[31] -= 1;
+    let mut n_minus_2 = n;
+    n_minus_2[31] -= 2;
+
+    assert!(sm2_derive_public_key(&[0u8; 32]).is_none());
+    assert!(sm2_derive_public_key(&n).is_none());
+    assert!(sm2_derive_public_key(&n_minus_1).is_none());
+    assert!(sm2_derive_public_key(&n_minus_2).is_some());
+    assert!(sm2_derive_public_key(&[0xFF; 32]).is_none());
+}
+
 #[test]
 fn test_sm2_sign_verify() {
     // 生成密钥对
@@ -0,0 +1,123 @@
+// SM2强类型测试
+
+use gm_sdk::sm2::SM2_N;
+use gm_sdk::{Sm2Ciphertext, Sm2CiphertextFormat, Sm2PrivateKey, Sm2PublicKey, Sm2Signature};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+const PRIVATE_KEY_HEX: &str = "3945208F7B2144B13F36E38AC6D39F95889393692860B51A42FB81EF4DF7C5B8";
+
+#[test]
+fn test_sm2_private_key_range_and_hex() {
+    let key = Sm2PrivateKey::from_hex(PRIVATE_KEY_HEX).unwrap();
+    assert_eq!(key.to_hex(), PRIVATE_KEY_HEX);
+    assert_eq!(key.to_bytes().to_vec(), hex_to_bytes(PRIVATE_KEY_HEX));
+    assert_eq!(Sm2PrivateKey::from_hex(&PRIVATE_KEY_HEX.to_lowercase()), Some(key.clone()));
+
+    // 0、n-1、n 均不是合法私钥
+    assert!(Sm2PrivateKey::from_bytes(&[0u8; 32]).is_none());
+    let mut n_minus_1 = SM2_N;
+    n_minus_1[31] -= 1;
+    assert!(Sm2PrivateKey::from_bytes(&n_minus_1).is_none());
+    assert!(Sm2PrivateKey::from_bytes(&SM2_N).is_none());
+
+    // 长度或字符非法
+    assert!(Sm2PrivateKey::from_hex(&PRIVATE_KEY_HEX[..62]).is_none());
+    assert!(Sm2PrivateKey::from_hex(&format!("+{}", &PRIVATE_KEY_HEX[1..])).is_none());
+}
+
+#[test]
+fn test_sm2_debug_output() {
+    let key = Sm2PrivateKey::from_hex(PRIVATE_KEY_HEX).unwrap();
+    let debug = format!("{:?}", key);
+    assert_eq!(debug, "Sm2PrivateKey([REDACTED])");
+    assert!(!debug.contains(&PRIVATE_KEY_HEX[..8]));
+
+    let public_key = key.public_key();
+    assert_eq!(format!("{:?}", public_key), format!("Sm2PublicKey({})", public_key.to_hex()));
+}
+
+#[test]
+fn test_sm2_typed_sign_verify() {
+    let key = Sm2PrivateKey::generate();
+    let public_key = key.public_key();
+    assert_eq!(Sm2PublicKey::from_hex(&public_key.to_hex()), Some(public_key));
+
+    let signature = key.sign(b"message digest");
+    assert!(public_key.verify(b"message digest", &signature));
+    assert!(!public_key.verify(b"message digesT", &signature));
+    assert_eq!(Sm2Signature::from_hex(&signature.to_hex()), Some(signature));
+    assert_eq!(signature.r().to_vec(), signature.to_bytes()[..32].to_vec());
+    assert_eq!(signature.s().to_vec(), signature.to_bytes()[32..].to_vec());
+
+    let signature = key.sign_with_id(b"ALICE123@YAHOO.COM", b"message digest").unwrap();
+    assert!(public_key.verify_with_id(b"ALICE123@YAHOO.COM", b"message digest", &signature));
+    assert!(!public_key.verify(b"message digest", &signature));
+
+    let digest = [0x5Au8; 32];
+    let signature = key.sign_digest(&digest);
+    assert!(public_key.verify_digest(&digest, &signature));
+}
+
+#[test]
+fn test_sm2_signature_range() {
+    let mut bytes = [1u8; 64];
+    assert!(Sm2Signature::from_bytes(&bytes).is_some());
+
+    // r = 0
+    bytes[..32].copy_from_slice(&[0u8; 32]);
+    assert!(Sm2Signature::from_bytes(&bytes).is_none());
+
+    // s = n
+    bytes[..32].copy_from_slice(&[1u8; 32]);
+    bytes[32..].copy_from_slice(&SM2_N);
+    assert!(Sm2Signature::from_bytes(&bytes).is_none());
+}
+
+#[test]
+fn test_sm2_typed_encrypt_decrypt() {
+    let key = Sm2PrivateKey::generate();
+    let ciphertext = key.public_key().encrypt(b"encryption standard").unwrap();
+    assert_eq!(key.decrypt(&ciphertext).unwrap(), b"encryption standard");
+    assert!(key.public_key().encrypt(b"").is_none());
+
+    for format in [
+        Sm2CiphertextFormat::C1C3C2,
+        Sm2CiphertextFormat::C1C2C3,
+        Sm2CiphertextFormat::C1C3C2Raw,
+        Sm2CiphertextFormat::C1C2C3Raw,
+        Sm2CiphertextFormat::Der,
+    ] {
+        let encoded = ciphertext.to_format(format);
+        assert_eq!(Sm2Ciphertext::from_format(&encoded, format), Some(ciphertext.clone()));
+    }
+    assert_eq!(Sm2Ciphertext::from_hex(&ciphertext.to_hex()), Some(ciphertext.clone()));
+
+    // 他人私钥无法解密
+    assert!(Sm2PrivateKey::generate().decrypt(&ciphertext).is_none());
+}
+
+#[test]
+fn test_sm2_ciphertext_rejects_invalid() {
+    let key = Sm2PrivateKey::generate();
+    let ciphertext = key.public_key().encrypt(b"abc").unwrap();
+    let bytes = ciphertext.as_bytes();
+
+    // 截断到无C2
+    assert!(Sm2Ciphertext::from_bytes(&bytes[..97]).is_none());
+
+    // 前缀错误
+    let mut bad = bytes.to_vec();
+    bad[0] = 0x05;
+    assert!(Sm2Ciphertext::from_bytes(&bad).is_none());
+
+    // C1不在曲线上
+    let mut bad = bytes.to_vec();
+    bad[64] ^= 1;
+    assert!(Sm2Ciphertext::from_bytes(&bad).is_none());
+}
@@ -0,0 +1,38 @@
+// SM2 Z值测试
+
+use gm_sdk::sm2::{SM2_DEFAULT_ID, SM2_MAX_ID_LEN, sm2_compute_z, sm2_generate_keypair};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_sm2_compute_z_standard() {
+    // GB/T 32918.5 示例公钥，ID = 1234567812345678
+    let public_key: [u8; 64] = hex_to_bytes(
+        "09F9DF311E5421A150DD7D161E4BC5C672179FAD1833FC076BB08FF356F35020\
+         CCEA490CE26775A52DC6EA718CC1AA600AED05FBF35E084A6632F6072DA9AD13",
+    )
+    .try_into()
+    .unwrap();
+    let expected = hex_to_bytes("B2E14C5C79C6DF5B85F4FE7ED8DB7A262B9DA7E07CCB0EA9F4747B8CCDA8A4F3");
+    assert_eq!(sm2_compute_z(&public_key, SM2_DEFAULT_ID).unwrap().to_vec(), expected);
+}
+
+#[test]
+fn test_sm2_compute_z_id_length() {
+    let (_, public_key) = sm2_generate_keypair();
+
+    // 空ID与最大长度ID均合法，且结果互不相同
+    let empty = sm2_compute_z(&public_key, b"").unwrap();
+    let max = sm2_compute_z(&public_key, &vec![0x41; SM2_MAX_ID_LEN]).unwrap();
+    let default = sm2_compute_z(&public_key, SM2_DEFAULT_ID).unwrap();
+    assert_ne!(empty, max);
+    assert_ne!(empty, default);
+
+    // 超过8191字节时ENTL溢出，拒绝计算
+    assert!(sm2_compute_z(&public_key, &vec![0x41; SM2_MAX_ID_LEN + 1]).is_none());
+}
@@ -0,0 +1,36 @@
+// PBKDF2-HMAC-SM3测试
+
+use gm_sdk::sm3::pbkdf2_hmac_sm3;
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_pbkdf2_hmac_sm3_single_iteration() {
+    // 输出超过一个块，覆盖块计数器
+    let mut out = [0u8; 40];
+    pbkdf2_hmac_sm3(b"password", b"salt", 1, &mut out);
+    assert_eq!(
+        out.to_vec(),
+        hex_to_bytes("4612F922A1FDCEFAF4312FC6F8F3322B489CBF24F2EA361B44C2BD8FA2C6DCB0E2BEA084418FFB4F")
+    );
+}
+
+#[test]
+fn test_pbkdf2_hmac_sm3_iterations() {
+    let mut out = [0u8; 32];
+    pbkdf2_hmac_sm3(b"password", b"salt", 1000, &mut out);
+    assert_eq!(
+        out.to_vec(),
+        hex_to_bytes("E8B635A41DFE5AAAB7CF828CFF6F3608E22CAC59BA16EDD70E000B293D00BC91")
+    );
+
+    // 输出前缀与长度无关
+    let mut short = [0u8; 16];
+    pbkdf2_hmac_sm3(b"password", b"salt", 1000, &mut short);
+    assert_eq!(short, out[..16]);
+}
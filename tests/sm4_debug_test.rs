@@ -22,8 +22,8 @@ fn test_sm4_debug() {
     let mut round_keys = [0u32; 32];
     key_expansion(&key, &mut round_keys);
     println!("Round keys:");
-    for i in 0..32 {
-        println!("rk[{}] = 0x{:08x}", i, round_keys[i]);
+    for (i, rk) in round_keys.iter().enumerate() {
+        println!("rk[{}] = 0x{:08x}", i, rk);
     }
     
     // 测试CBC加密
@@ -64,8 +64,8 @@ fn test_sm4_ecb_encrypt_decrypt_standard() {
     // 测试加密
     let mut ciphertext = [0u8; 16];
     sm4_encrypt_cbc(&key, &iv, &plaintext, &mut ciphertext);
-    // 注意：这里使用CBC API模拟ECB，结果可能与标准ECB不同
-    // 实际应用中应该使用专门的ECB实现
+    // 单个分组且IV为0时，CBC的结果与ECB相同
+    assert_eq!(ciphertext, expected_ciphertext, "SM4 加密失败");
     
     // 测试解密
     let mut decrypted_plaintext = [0u8; 16];
@@ -0,0 +1,218 @@
+// X.509证书与PKCS#10证书请求生成测试
+
+use gm_sdk::{
+    Sm2PrivateKey, X509BasicConstraints, X509CertificateBuilder, X509Csr, X509CsrBuilder, X509Extension,
+    X509GeneralName, X509KeyUsage, X509Name,
+};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+// 2030-01-01 00:00:00 UTC 与 2060-01-01 00:00:00 UTC
+const NOT_BEFORE: i64 = 1893456000;
+const NOT_AFTER: i64 = 2840140800;
+
+fn ca_extensions(builder: X509CertificateBuilder, path_len: Option<u32>) -> X509CertificateBuilder {
+    builder
+        .extension(X509Extension::basic_constraints(X509BasicConstraints { ca: true, path_len }))
+        .extension(X509Extension::key_usage(X509KeyUsage::KEY_CERT_SIGN | X509KeyUsage::CRL_SIGN).unwrap())
+}
+
+#[test]
+fn test_x509_name_from_attributes() {
+    let name = X509Name::from_attributes(&[("C", "CN"), ("O", "国密测试"), ("2.5.4.3", "Test CA")]).unwrap();
+    assert_eq!(name.to_string(), "C=CN, O=国密测试, CN=Test CA");
+    assert_eq!(name.common_name(), Some("Test CA"));
+    // C 为 PrintableString，CN 为 UTF8String
+    let der = name.as_der();
+    assert!(der.windows(9).any(|w| w == hex_to_bytes("06035504061302434E")));
+    assert!(der.windows(7).any(|w| w == hex_to_bytes("06035504030C07")));
+
+    assert!(X509Name::from_attributes(&[("C", "中国")]).is_none());
+    assert!(X509Name::from_attributes(&[("CN", "")]).is_none());
+    assert!(X509Name::from_attributes(&[("XX", "value")]).is_none());
+}
+
+#[test]
+fn test_x509_key_usage_encoding() {
+    // 命名位列表去掉末尾0位
+    let cases = [
+        (X509KeyUsage::DIGITAL_SIGNATURE, "03020780"),
+        (X509KeyUsage::KEY_CERT_SIGN | X509KeyUsage::CRL_SIGN, "03020106"),
+        (X509KeyUsage::DIGITAL_SIGNATURE | X509KeyUsage::KEY_ENCIPHERMENT, "030205A0"),
+        (X509KeyUsage::DECIPHER_ONLY, "0303070080"),
+    ];
+    for (usage, expected) in cases {
+        assert_eq!(X509Extension::key_usage(usage).unwrap().value, hex_to_bytes(expected));
+    }
+
+    // RFC 5280要求至少设置一位
+    assert!(X509Extension::key_usage(X509KeyUsage(0)).is_none());
+}
+
+#[test]
+fn test_x509_self_signed_root() {
+    let key = Sm2PrivateKey::generate();
+    let name = X509Name::from_attributes(&[("C", "CN"), ("O", "GM Test"), ("CN", "Root")]).unwrap();
+    let root = ca_extensions(X509CertificateBuilder::new(name.clone(), key.public_key()), Some(1))
+        .serial_number(&[0x01, 0x02])
+        .validity(NOT_BEFORE, NOT_AFTER)
+        .self_signed(&key)
+        .unwrap();
+
+    assert_eq!(root.version(), 3);
+    assert_eq!(root.serial_number(), [0x01, 0x02]);
+    assert_eq!(root.subject(), &name);
+    assert_eq!(root.issuer(), &name);
+    assert_eq!(root.not_before(), NOT_BEFORE);
+    assert_eq!(root.not_after(), NOT_AFTER);
+    assert_eq!(root.public_key(), key.public_key());
+    assert_eq!(root.basic_constraints(), Some(X509BasicConstraints { ca: true, path_len: Some(1) }));
+    assert_eq!(root.key_usage(), Some(X509KeyUsage::KEY_CERT_SIGN | X509KeyUsage::CRL_SIGN));
+    assert!(root.is_self_signed());
+
+    // SKI 为 SM3(公钥) 的前20字节，自签证书的AKI与SKI相同
+    let mut point = vec![0x04];
+    point.extend_from_slice(&key.public_key().to_bytes());
+    assert_eq!(root.subject_key_identifier(), Some(gm_sdk::sm3_hash(&point)[..20].to_vec()));
+    assert_eq!(root.authority_key_identifier(), root.subject_key_identifier());
+
+    // 私钥与主体公钥不匹配
+    let other = Sm2PrivateKey::generate();
+    assert!(X509CertificateBuilder::new(name, key.public_key()).self_signed(&other).is_none());
+}
+
+#[test]
+fn test_x509_issue_chain() {
+    let root_key = Sm2PrivateKey::generate();
+    let root_name = X509Name::from_attributes(&[("CN", "Root")]).unwrap();
+    let root = ca_extensions(X509CertificateBuilder::new(root_name, root_key.public_key()), Some(0))
+        .validity(NOT_BEFORE, NOT_AFTER)
+        .self_signed(&root_key)
+        .unwrap();
+
+    let ca_key = Sm2PrivateKey::generate();
+    let ca_name = X509Name::from_attributes(&[("CN", "Intermediate")]).unwrap();
+    let ca = ca_extensions(X509CertificateBuilder::new(ca_name, ca_key.public_key()), None)
+        .validity(NOT_BEFORE, NOT_AFTER)
+        .sign(&root, &root_key)
+        .unwrap();
+    assert!(ca.verify_issued_by(&root));
+    assert_eq!(ca.authority_key_identifier(), root.subject_key_identifier());
+
+    let leaf_key = Sm2PrivateKey::generate();
+    let leaf_name = X509Name::from_attributes(&[("CN", "leaf.example.com")]).unwrap();
+    let names = [
+        X509GeneralName::Dns("leaf.example.com".to_string()),
+        X509GeneralName::Ip(vec![192, 168, 0, 1]),
+        X509GeneralName::Uri("https://leaf.example.com/".to_string()),
+    ];
+    let leaf = X509CertificateBuilder::new(leaf_name, leaf_key.public_key())
+        .validity(NOT_BEFORE, NOT_AFTER)
+        .extension(X509Extension::basic_constraints(X509BasicConstraints { ca: false, path_len: None }))
+        .extension(X509Extension::key_usage(X509KeyUsage::DIGITAL_SIGNATURE).unwrap())
+        .extension(X509Extension::extended_key_usage(&["1.3.6.1.5.5.7.3.1"]).unwrap())
+        .extension(X509Extension::subject_alt_names(&names).unwrap())
+        .sign(&ca, &ca_key)
+        .unwrap();
+    assert!(leaf.verify_issued_by(&ca));
+    assert!(!leaf.verify_issued_by(&root));
+    assert_eq!(leaf.issuer(), ca.subject());
+    assert_eq!(leaf.subject_alt_names(), Some(names.to_vec()));
+    assert_eq!(leaf.extended_key_usage(), Some(vec!["1.3.6.1.5.5.7.3.1".to_string()]));
+    assert!(!leaf.extension("2.5.29.19").unwrap().critical);
+
+    // 签发者私钥与证书不匹配
+    let builder =
+        X509CertificateBuilder::new(X509Name::from_attributes(&[("CN", "x")]).unwrap(), leaf_key.public_key());
+    assert!(builder.sign(&ca, &root_key).is_none());
+}
+
+#[test]
+fn test_x509_csr() {
+    let key = Sm2PrivateKey::generate();
+    let subject = X509Name::from_attributes(&[("C", "CN"), ("CN", "client")]).unwrap();
+    let names = [X509GeneralName::Email("client@example.com".to_string())];
+    let csr = X509CsrBuilder::new(subject.clone())
+        .extension(X509Extension::subject_alt_names(&names).unwrap())
+        .sign(&key)
+        .unwrap();
+    assert!(csr.verify());
+    assert_eq!(csr.subject(), &subject);
+    assert_eq!(csr.public_key(), key.public_key());
+    assert_eq!(csr.extensions().len(), 1);
+
+    let pem = csr.to_pem();
+    assert!(pem.starts_with("-----BEGIN CERTIFICATE REQUEST-----\n"));
+    assert_eq!(X509Csr::from_pem(&pem), Some(csr.clone()));
+
+    // 无扩展的请求
+    let empty = X509CsrBuilder::new(subject.clone()).sign(&key).unwrap();
+    assert!(empty.verify());
+    assert!(empty.extensions().is_empty());
+
+    // 由证书请求签发
+    let ca_key = Sm2PrivateKey::generate();
+    let ca = ca_extensions(
+        X509CertificateBuilder::new(X509Name::from_attributes(&[("CN", "CA")]).unwrap(), ca_key.public_key()),
+        None,
+    )
+    .self_signed(&ca_key)
+    .unwrap();
+    let certificate = X509CertificateBuilder::from_csr(&csr).unwrap().sign(&ca, &ca_key).unwrap();
+    assert_eq!(certificate.subject(), &subject);
+    assert_eq!(certificate.public_key(), key.public_key());
+    assert_eq!(certificate.subject_alt_names(), Some(names.to_vec()));
+    assert!(certificate.verify_issued_by(&ca));
+
+    // 篡改后的请求自签名无效
+    let mut der = csr.as_der().to_vec();
+    let position = der.windows(6).position(|w| w == b"client").unwrap();
+    der[position] = b'C';
+    let tampered = X509Csr::from_der(&der).unwrap();
+    assert!(!tampered.verify());
+    assert!(X509CertificateBuilder::from_csr(&tampered).is_none());
+
+    // 请求CA证书的扩展不会被沿用
+    let csr = X509CsrBuilder::new(subject.clone())
+        .extension(X509Extension::basic_constraints(X509BasicConstraints { ca: true, path_len: None }))
+        .extension(X509Extension::key_usage(X509KeyUsage::KEY_CERT_SIGN | X509KeyUsage::CRL_SIGN).unwrap())
+        .extension(X509Extension::subject_alt_names(&names).unwrap())
+        .extension(X509Extension::subject_key_identifier(&[0x01; 20]))
+        .sign(&key)
+        .unwrap();
+    assert_eq!(csr.extensions().len(), 4);
+    let certificate = X509CertificateBuilder::from_csr(&csr).unwrap().sign(&ca, &ca_key).unwrap();
+    assert!(certificate.basic_constraints().is_none());
+    assert!(certificate.key_usage().is_none());
+    assert_ne!(certificate.subject_key_identifier(), Some(vec![0x01; 20]));
+    assert_eq!(certificate.subject_alt_names(), Some(names.to_vec()));
+}
+
+#[test]
+fn test_x509_builder_serial_number_and_validity() {
+    let key = Sm2PrivateKey::generate();
+    let name = X509Name::from_attributes(&[("CN", "Serial")]).unwrap();
+    let builder = X509CertificateBuilder::new(name, key.public_key()).validity(NOT_BEFORE, NOT_AFTER);
+
+    // 空序列号、零序列号
+    assert!(builder.clone().serial_number(&[]).self_signed(&key).is_none());
+    assert!(builder.clone().serial_number(&[0x00, 0x00]).self_signed(&key).is_none());
+
+    // 20字节为上限；最高位为1时编码补0x00，20字节的这种序列号超出上限
+    let certificate = builder.clone().serial_number(&[0x7F; 20]).self_signed(&key).unwrap();
+    assert_eq!(certificate.serial_number(), [0x7F; 20]);
+    assert!(builder.clone().serial_number(&[0x01; 21]).self_signed(&key).is_none());
+    assert!(builder.clone().serial_number(&[0x80; 20]).self_signed(&key).is_none());
+    let certificate = builder.clone().serial_number(&[0x80; 19]).self_signed(&key).unwrap();
+    assert_eq!(certificate.serial_number().len(), 20);
+    // 前导零字节不计入长度
+    let mut padded = vec![0x00; 4];
+    padded.extend_from_slice(&[0x7F; 20]);
+    assert!(builder.clone().serial_number(&padded).self_signed(&key).is_some());
+
+    // notBefore晚于notAfter
+    assert!(builder.clone().validity(NOT_AFTER, NOT_BEFORE).self_signed(&key).is_none());
+    assert!(builder.clone().validity(NOT_BEFORE, NOT_BEFORE).self_signed(&key).is_some());
+}
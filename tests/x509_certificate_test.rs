@@ -0,0 +1,173 @@
+// X.509证书解析与验证测试
+
+use gm_sdk::{X509Certificate, X509GeneralName, X509KeyUsage};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// 以下证书由 OpenSSL 3 以 -sm3 -sigopt distid:1234567812345678 签发
+const ROOT_PEM: &str = "-----BEGIN CERTIFICATE-----
+MIIByDCCAW+gAwIBAgIBATAKBggqgRzPVQGDdTA5MQswCQYDVQQGEwJDTjEQMA4G
+A1UECgwHR00gVGVzdDEYMBYGA1UEAwwPR00gVGVzdCBSb290IENBMCAXDTI2MTAx
+NzEzMjUzNVoYDzIxMjYwOTIzMTMyNTM1WjA5MQswCQYDVQQGEwJDTjEQMA4GA1UE
+CgwHR00gVGVzdDEYMBYGA1UEAwwPR00gVGVzdCBSb290IENBMFkwEwYHKoZIzj0C
+AQYIKoEcz1UBgi0DQgAEOtO41x3Zso0a1kffky6mVbHUfcY8H61t/a6ThIRC3tQE
+Opv31xELnqrVclW9Vv3rSGJtk1M/NVmpeEMLeafSR6NmMGQwHwYDVR0jBBgwFoAU
+Dms2B5SwmtTQGCO7Sg/3xSsUxYIwEgYDVR0TAQH/BAgwBgEB/wIBATAOBgNVHQ8B
+Af8EBAMCAQYwHQYDVR0OBBYEFA5rNgeUsJrU0Bgju0oP98UrFMWCMAoGCCqBHM9V
+AYN1A0cAMEQCIHIAy6iY2enlAreQoryu+AWvEildR1whLr23uDYgJ9NsAiBct6Kw
+axikS4Je7DkiUHHxy9Z3Vik7f6svHFgL2ZULIQ==
+-----END CERTIFICATE-----
+";
+
+const LEAF_PEM: &str = "-----BEGIN CERTIFICATE-----
+MIICJDCCAcmgAwIBAgIIEjRWeJCrze8wCgYIKoEcz1UBg3UwOTELMAkGA1UEBhMC
+Q04xEDAOBgNVBAoMB0dNIFRlc3QxGDAWBgNVBAMMD0dNIFRlc3QgUm9vdCBDQTAg
+Fw0yNjEwMTcxMzI1MzVaGA8yMTI2MDkyMzEzMjUzNVowPDELMAkGA1UEBhMCQ04x
+EDAOBgNVBAoMB0dNIFRlc3QxGzAZBgNVBAMMEnNlcnZlci5leGFtcGxlLmNvbTBZ
+MBMGByqGSM49AgEGCCqBHM9VAYItA0IABNzlksRHk8qw/FktDq6NWhcRHoWLNEs3
+kcgxV7Iaism77IV19S4796ZVFdBhHm009BCHxPPY2iVujd7+8hADU3+jgbUwgbIw
+CQYDVR0TBAIwADAOBgNVHQ8BAf8EBAMCBaAwHQYDVR0lBBYwFAYIKwYBBQUHAwEG
+CCsGAQUFBwMCMDYGA1UdEQQvMC2CEnNlcnZlci5leGFtcGxlLmNvbYcEfwAAAYER
+YWRtaW5AZXhhbXBsZS5jb20wHQYDVR0OBBYEFPAyJQ3MIB7Gk0t6NoSxOJ17Osmd
+MB8GA1UdIwQYMBaAFA5rNgeUsJrU0Bgju0oP98UrFMWCMAoGCCqBHM9VAYN1A0kA
+MEYCIQCxzNB/KQFq4nL3rjh5GxOQB0CsLJE2iUzLLt2ro9j2VwIhAOdWdkgblJCW
+a+X1BS7HGtk0M6lPVXDlyvRMThT4t5ru
+-----END CERTIFICATE-----
+";
+
+// 同一根CA密钥，OpenSSL 3 未指定distid时以空身份标识自签
+const OPENSSL_EMPTY_ID_ROOT_PEM: &str = "-----BEGIN CERTIFICATE-----
+MIIB2zCCAYKgAwIBAgIUUcFahpkG4ehUk2VZ1fpu3imEo64wCgYIKoEcz1UBg3Uw
+OTELMAkGA1UEBhMCQ04xEDAOBgNVBAoMB0dNIFRlc3QxGDAWBgNVBAMMD0dNIFRl
+c3QgUm9vdCBDQTAgFw0yNjEwMTcxMzI1MTdaGA8yMTI2MDkyMzEzMjUxN1owOTEL
+MAkGA1UEBhMCQ04xEDAOBgNVBAoMB0dNIFRlc3QxGDAWBgNVBAMMD0dNIFRlc3Qg
+Um9vdCBDQTBZMBMGByqGSM49AgEGCCqBHM9VAYItA0IABDrTuNcd2bKNGtZH35Mu
+plWx1H3GPB+tbf2uk4SEQt7UBDqb99cRC56q1XJVvVb960hibZNTPzVZqXhDC3mn
+0kejZjBkMB0GA1UdDgQWBBQOazYHlLCa1NAYI7tKD/fFKxTFgjAfBgNVHSMEGDAW
+gBQOazYHlLCa1NAYI7tKD/fFKxTFgjASBgNVHRMBAf8ECDAGAQH/AgEBMA4GA1Ud
+DwEB/wQEAwIBBjAKBggqgRzPVQGDdQNHADBEAiAUsBYs7y2CxME+wG6TIQWd2MQ/
+EL0DZzJtQMfhGYbgVwIgD+YyHNQC5EWuieNdUeDIkwO428qRQM6xA4wMp1zDRi0=
+-----END CERTIFICATE-----
+";
+
+// 2026-10-17 13:25:35 UTC 与 2126-09-23 13:25:35 UTC
+const NOT_BEFORE: i64 = 1792243535;
+const NOT_AFTER: i64 = 4945843535;
+
+#[test]
+fn test_x509_parse_root() {
+    let root = X509Certificate::from_pem(ROOT_PEM).unwrap();
+    assert_eq!(root.version(), 3);
+    assert_eq!(root.serial_number(), [1]);
+    assert_eq!(root.subject().to_string(), "C=CN, O=GM Test, CN=GM Test Root CA");
+    assert_eq!(root.subject().common_name(), Some("GM Test Root CA"));
+    assert_eq!(root.issuer(), root.subject());
+
+    // notBefore为UTCTime，notAfter为GeneralizedTime
+    assert_eq!(root.not_before(), NOT_BEFORE);
+    assert_eq!(root.not_after(), NOT_AFTER);
+
+    let constraints = root.basic_constraints().unwrap();
+    assert!(constraints.ca);
+    assert_eq!(constraints.path_len, Some(1));
+    assert!(root.extension("2.5.29.19").unwrap().critical);
+    assert_eq!(root.key_usage(), Some(X509KeyUsage::KEY_CERT_SIGN | X509KeyUsage::CRL_SIGN));
+    assert_eq!(root.subject_key_identifier(), Some(hex_to_bytes("0E6B360794B09AD4D01823BB4A0FF7C52B14C582")));
+    assert!(root.is_self_signed());
+}
+
+#[test]
+fn test_x509_parse_leaf() {
+    let root = X509Certificate::from_pem(ROOT_PEM).unwrap();
+    let leaf = X509Certificate::from_pem(LEAF_PEM).unwrap();
+    assert_eq!(leaf.serial_number(), hex_to_bytes("1234567890ABCDEF"));
+    assert_eq!(leaf.subject().common_name(), Some("server.example.com"));
+    assert_eq!(leaf.issuer(), root.subject());
+
+    let constraints = leaf.basic_constraints().unwrap();
+    assert!(!constraints.ca);
+    let usage = leaf.key_usage().unwrap();
+    assert!(usage.contains(X509KeyUsage::DIGITAL_SIGNATURE | X509KeyUsage::KEY_ENCIPHERMENT));
+    assert!(!usage.contains(X509KeyUsage::KEY_CERT_SIGN));
+    assert_eq!(
+        leaf.extended_key_usage(),
+        Some(vec!["1.3.6.1.5.5.7.3.1".to_string(), "1.3.6.1.5.5.7.3.2".to_string()])
+    );
+    assert_eq!(
+        leaf.subject_alt_names(),
+        Some(vec![
+            X509GeneralName::Dns("server.example.com".to_string()),
+            X509GeneralName::Ip(vec![127, 0, 0, 1]),
+            X509GeneralName::Email("admin@example.com".to_string()),
+        ])
+    );
+    assert_eq!(leaf.subject_key_identifier(), Some(hex_to_bytes("F032250DCC201EC6934B7A3684B1389D7B3AC99D")));
+    assert_eq!(leaf.authority_key_identifier(), root.subject_key_identifier());
+
+    assert!(leaf.is_valid_at(NOT_BEFORE));
+    assert!(leaf.is_valid_at(NOT_AFTER));
+    assert!(!leaf.is_valid_at(NOT_BEFORE - 1));
+    assert!(!leaf.is_valid_at(NOT_AFTER + 1));
+}
+
+#[test]
+fn test_x509_verify_signature() {
+    let root = X509Certificate::from_pem(ROOT_PEM).unwrap();
+    let leaf = X509Certificate::from_pem(LEAF_PEM).unwrap();
+    assert!(leaf.verify_issued_by(&root));
+    assert!(leaf.verify_signature(&root.public_key()));
+    assert!(!leaf.verify_signature(&leaf.public_key()));
+    assert!(!leaf.is_self_signed());
+    assert!(!root.verify_issued_by(&leaf));
+
+    // 空身份标识签发的证书只能用空标识验证
+    let openssl_root = X509Certificate::from_pem(OPENSSL_EMPTY_ID_ROOT_PEM).unwrap();
+    assert_eq!(openssl_root.public_key(), root.public_key());
+    assert!(!openssl_root.verify_signature(&root.public_key()));
+    assert!(openssl_root.verify_signature_with_id(&root.public_key(), b""));
+}
+
+#[test]
+fn test_x509_der_round_trip() {
+    let leaf = X509Certificate::from_pem(LEAF_PEM).unwrap();
+    assert_eq!(X509Certificate::from_der(leaf.as_der()), Some(leaf.clone()));
+    assert_eq!(leaf.to_pem(), format!("{}\n", LEAF_PEM.trim_end()));
+}
+
+#[test]
+fn test_x509_rejects_invalid() {
+    let root = X509Certificate::from_pem(ROOT_PEM).unwrap();
+    let leaf = X509Certificate::from_pem(LEAF_PEM).unwrap();
+    let der = leaf.as_der().to_vec();
+
+    // 尾随数据与截断
+    let mut trailing = der.clone();
+    trailing.push(0);
+    assert!(X509Certificate::from_der(&trailing).is_none());
+    assert!(X509Certificate::from_der(&der[..der.len() - 1]).is_none());
+
+    // 修改主体名称中的一个字符：仍可解析，但签名无效
+    let position = der.windows(6).position(|w| w == b"server").unwrap();
+    let mut tampered = der.clone();
+    tampered[position] = b'S';
+    let tampered = X509Certificate::from_der(&tampered).unwrap();
+    assert!(!tampered.verify_issued_by(&root));
+
+    // 签名算法OID改为 1.2.156.10197.1.502（SM2加密标识）
+    let mut wrong_algorithm = der.clone();
+    for i in 0..wrong_algorithm.len() - 8 {
+        if wrong_algorithm[i..i + 8] == hex_to_bytes("2A811CCF55018375")[..] {
+            wrong_algorithm[i + 7] = 0x76;
+        }
+    }
+    assert!(X509Certificate::from_der(&wrong_algorithm).is_none());
+
+    // PEM标签不符
+    let pem = LEAF_PEM.replace("CERTIFICATE", "X509 CRL");
+    assert!(X509Certificate::from_pem(&pem).is_none());
+}
@@ -0,0 +1,246 @@
+// X.509 CRL解析与吊销检查测试
+
+use gm_sdk::{
+    Sm2PrivateKey, X509BasicConstraints, X509Certificate, X509CertificateBuilder, X509Crl, X509CrlReason,
+    X509Extension, X509KeyUsage, X509Name, X509PathErrorKind, X509PathValidator, X509TrustStore,
+};
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+// 根CA与终端证书同 x509_certificate_test，由 OpenSSL 3 以 -sigopt distid:1234567812345678 签发
+const ROOT_PEM: &str = "-----BEGIN CERTIFICATE-----
+MIIByDCCAW+gAwIBAgIBATAKBggqgRzPVQGDdTA5MQswCQYDVQQGEwJDTjEQMA4G
+A1UECgwHR00gVGVzdDEYMBYGA1UEAwwPR00gVGVzdCBSb290IENBMCAXDTI2MTAx
+NzEzMjUzNVoYDzIxMjYwOTIzMTMyNTM1WjA5MQswCQYDVQQGEwJDTjEQMA4GA1UE
+CgwHR00gVGVzdDEYMBYGA1UEAwwPR00gVGVzdCBSb290IENBMFkwEwYHKoZIzj0C
+AQYIKoEcz1UBgi0DQgAEOtO41x3Zso0a1kffky6mVbHUfcY8H61t/a6ThIRC3tQE
+Opv31xELnqrVclW9Vv3rSGJtk1M/NVmpeEMLeafSR6NmMGQwHwYDVR0jBBgwFoAU
+Dms2B5SwmtTQGCO7Sg/3xSsUxYIwEgYDVR0TAQH/BAgwBgEB/wIBATAOBgNVHQ8B
+Af8EBAMCAQYwHQYDVR0OBBYEFA5rNgeUsJrU0Bgju0oP98UrFMWCMAoGCCqBHM9V
+AYN1A0cAMEQCIHIAy6iY2enlAreQoryu+AWvEildR1whLr23uDYgJ9NsAiBct6Kw
+axikS4Je7DkiUHHxy9Z3Vik7f6svHFgL2ZULIQ==
+-----END CERTIFICATE-----
+";
+
+const LEAF_PEM: &str = "-----BEGIN CERTIFICATE-----
+MIICJDCCAcmgAwIBAgIIEjRWeJCrze8wCgYIKoEcz1UBg3UwOTELMAkGA1UEBhMC
+Q04xEDAOBgNVBAoMB0dNIFRlc3QxGDAWBgNVBAMMD0dNIFRlc3QgUm9vdCBDQTAg
+Fw0yNjEwMTcxMzI1MzVaGA8yMTI2MDkyMzEzMjUzNVowPDELMAkGA1UEBhMCQ04x
+EDAOBgNVBAoMB0dNIFRlc3QxGzAZBgNVBAMMEnNlcnZlci5leGFtcGxlLmNvbTBZ
+MBMGByqGSM49AgEGCCqBHM9VAYItA0IABNzlksRHk8qw/FktDq6NWhcRHoWLNEs3
+kcgxV7Iaism77IV19S4796ZVFdBhHm009BCHxPPY2iVujd7+8hADU3+jgbUwgbIw
+CQYDVR0TBAIwADAOBgNVHQ8BAf8EBAMCBaAwHQYDVR0lBBYwFAYIKwYBBQUHAwEG
+CCsGAQUFBwMCMDYGA1UdEQQvMC2CEnNlcnZlci5leGFtcGxlLmNvbYcEfwAAAYER
+YWRtaW5AZXhhbXBsZS5jb20wHQYDVR0OBBYEFPAyJQ3MIB7Gk0t6NoSxOJ17Osmd
+MB8GA1UdIwQYMBaAFA5rNgeUsJrU0Bgju0oP98UrFMWCMAoGCCqBHM9VAYN1A0kA
+MEYCIQCxzNB/KQFq4nL3rjh5GxOQB0CsLJE2iUzLLt2ro9j2VwIhAOdWdkgblJCW
+a+X1BS7HGtk0M6lPVXDlyvRMThT4t5ru
+-----END CERTIFICATE-----
+";
+
+// openssl ca -revoke leaf.pem -crl_reason keyCompromise 后
+// openssl ca -gencrl -sigopt distid:1234567812345678 生成，cRLNumber为0x1000
+const REVOKED_CRL_PEM: &str = "-----BEGIN X509 CRL-----
+MIIBITCBxwIBATAKBggqgRzPVQGDdTA5MQswCQYDVQQGEwJDTjEQMA4GA1UECgwH
+R00gVGVzdDEYMBYGA1UEAwwPR00gVGVzdCBSb290IENBFw0yNjEwMTcxMzQ5NDBa
+GA8yMTI2MDkyMzEzNDk0MFowKTAnAggSNFZ4kKvN7xcNMjYxMDE3MTM0OTQwWjAM
+MAoGA1UdFQQDCgEBoDAwLjAfBgNVHSMEGDAWgBQOazYHlLCa1NAYI7tKD/fFKxTF
+gjALBgNVHRQEBAICEAAwCgYIKoEcz1UBg3UDSQAwRgIhALlaBPFkdcVT987B5uvm
+mFhDCnUGhYbE+R5XjT/9QA/NAiEA22YGKnQKPT5s4eU+k2T1StME+FsVGG0iOv/z
+9wEKPW8=
+-----END X509 CRL-----
+";
+
+// 同一CA没有吊销记录的CRL，cRLNumber为0x1001
+const EMPTY_CRL_PEM: &str = "-----BEGIN X509 CRL-----
+MIH0MIGcAgEBMAoGCCqBHM9VAYN1MDkxCzAJBgNVBAYTAkNOMRAwDgYDVQQKDAdH
+TSBUZXN0MRgwFgYDVQQDDA9HTSBUZXN0IFJvb3QgQ0EXDTI2MTAxNzEzNDk0N1oY
+DzIxMjYwOTIzMTM0OTQ3WqAwMC4wHwYDVR0jBBgwFoAUDms2B5SwmtTQGCO7Sg/3
+xSsUxYIwCwYDVR0UBAQCAhABMAoGCCqBHM9VAYN1A0cAMEQCIBshRmARQtZ8FkUd
+17VTkRa4SiFyxy71eEZWLVx9wFKEAiAlR5ntz6LneW3CAlCTznyMlXI6RUyGPZSP
+Qsdh4jSmFw==
+-----END X509 CRL-----
+";
+
+// 由上面的根CA签发的中间CA（序列号2），openssl x509 -req -sigopt distid:1234567812345678
+const INTERMEDIATE_PEM: &str = "-----BEGIN CERTIFICATE-----
+MIIBzTCCAXSgAwIBAgIBAjAKBggqgRzPVQGDdTA5MQswCQYDVQQGEwJDTjEQMA4G
+A1UECgwHR00gVGVzdDEYMBYGA1UEAwwPR00gVGVzdCBSb290IENBMCAXDTI2MTAx
+NzE0MTA0NFoYDzIxMjYwOTIzMTQxMDQ0WjBBMQswCQYDVQQGEwJDTjEQMA4GA1UE
+CgwHR00gVGVzdDEgMB4GA1UEAwwXR00gVGVzdCBJbnRlcm1lZGlhdGUgQ0EwWTAT
+BgcqhkjOPQIBBggqgRzPVQGCLQNCAASp/6ECqJ+xE8IdhBm44+LFm3H5SSKZjBWP
+G/scpcpD1mRfyC1dnhaWoppVlAVHANYdWHmjlfyosrSViL9mfHXgo2MwYTAPBgNV
+HRMBAf8EBTADAQH/MA4GA1UdDwEB/wQEAwIBBjAdBgNVHQ4EFgQUjgOVxWmtR+IP
+VwpYQL10aaH16xEwHwYDVR0jBBgwFoAUDms2B5SwmtTQGCO7Sg/3xSsUxYIwCgYI
+KoEcz1UBg3UDRwAwRAIgFesSshWf1Qan1SPJZk7KV3QIzmLFOApS8xI9QNXIV8sC
+IEFJOtNGWztfHNj4q1Y3+sDmgN6ChrUeK1EpFWmTIEyP
+-----END CERTIFICATE-----
+";
+
+const INTERMEDIATE_KEY_HEX: &str = "A09030624534D6ACA1B717EE91C07795582E460886FCC35BD0D6D97666A7649C";
+
+// 2026-10-17 13:49:40 UTC 与 2126-09-23 13:49:40 UTC
+const THIS_UPDATE: i64 = 1792244980;
+const NEXT_UPDATE: i64 = 4945844980;
+const NOW: i64 = 2000000000;
+
+#[test]
+fn test_x509_crl_parse() {
+    let root = X509Certificate::from_pem(ROOT_PEM).unwrap();
+    let crl = X509Crl::from_pem(REVOKED_CRL_PEM).unwrap();
+    assert_eq!(crl.version(), 2);
+    assert_eq!(crl.issuer(), root.subject());
+    assert_eq!(crl.this_update(), THIS_UPDATE);
+    assert_eq!(crl.next_update(), Some(NEXT_UPDATE));
+    assert!(crl.is_valid_at(THIS_UPDATE));
+    assert!(!crl.is_valid_at(THIS_UPDATE - 1));
+    assert!(!crl.is_valid_at(NEXT_UPDATE + 1));
+    assert_eq!(crl.crl_number(), Some(vec![0x10, 0x00]));
+    assert_eq!(crl.authority_key_identifier(), root.subject_key_identifier());
+
+    let entries = crl.revoked_certificates();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].serial_number, hex_to_bytes("1234567890ABCDEF"));
+    assert_eq!(entries[0].revocation_date, THIS_UPDATE);
+    assert_eq!(entries[0].reason, Some(X509CrlReason::KeyCompromise));
+
+    let empty = X509Crl::from_pem(EMPTY_CRL_PEM).unwrap();
+    assert!(empty.revoked_certificates().is_empty());
+    assert_eq!(empty.crl_number(), Some(vec![0x10, 0x01]));
+
+    assert_eq!(X509Crl::from_der(crl.as_der()), Some(crl.clone()));
+    assert_eq!(crl.to_pem(), REVOKED_CRL_PEM);
+}
+
+#[test]
+fn test_x509_crl_lookup() {
+    let leaf = X509Certificate::from_pem(LEAF_PEM).unwrap();
+    let root = X509Certificate::from_pem(ROOT_PEM).unwrap();
+    let crl = X509Crl::from_pem(REVOKED_CRL_PEM).unwrap();
+    assert!(crl.is_revoked(&leaf));
+    assert!(!crl.is_revoked(&root));
+    assert!(!X509Crl::from_pem(EMPTY_CRL_PEM).unwrap().is_revoked(&leaf));
+
+    let entry = crl.revocation(&hex_to_bytes("1234567890ABCDEF")).unwrap();
+    assert_eq!(entry.reason, Some(X509CrlReason::KeyCompromise));
+    assert_eq!(entry.revocation_date, THIS_UPDATE);
+    assert!(crl.revocation(&hex_to_bytes("1234567890ABCDEE")).is_none());
+
+    // 序列号按INTEGER内容字节精确比较，多出前导零字节的不是同一序列号
+    assert!(crl.revocation(&hex_to_bytes("001234567890ABCDEF")).is_none());
+
+    assert_eq!(X509CrlReason::from_code(1), Some(X509CrlReason::KeyCompromise));
+    assert_eq!(X509CrlReason::from_code(7), None);
+    assert_eq!(X509CrlReason::RemoveFromCrl.code(), 8);
+    assert_eq!(X509CrlReason::KeyCompromise.to_string(), "keyCompromise");
+}
+
+#[test]
+fn test_x509_crl_signature() {
+    let root = X509Certificate::from_pem(ROOT_PEM).unwrap();
+    let leaf = X509Certificate::from_pem(LEAF_PEM).unwrap();
+    let crl = X509Crl::from_pem(REVOKED_CRL_PEM).unwrap();
+    assert!(crl.verify_issued_by(&root));
+    assert!(crl.verify_signature(&root.public_key()));
+    assert!(!crl.verify_signature_with_id(&root.public_key(), b""));
+    assert!(!crl.verify_issued_by(&leaf));
+
+    // 修改吊销记录中的序列号：仍可解析，但签名无效
+    let der = crl.as_der().to_vec();
+    let position = der.windows(8).position(|w| w == hex_to_bytes("1234567890ABCDEF")).unwrap();
+    let mut tampered = der.clone();
+    tampered[position + 7] ^= 1;
+    let tampered = X509Crl::from_der(&tampered).unwrap();
+    assert!(!tampered.is_revoked(&leaf));
+    assert!(!tampered.verify_issued_by(&root));
+
+    // 尾随数据、截断与PEM标签不符
+    let mut trailing = der.clone();
+    trailing.push(0);
+    assert!(X509Crl::from_der(&trailing).is_none());
+    assert!(X509Crl::from_der(&der[..der.len() - 1]).is_none());
+    assert!(X509Crl::from_pem(ROOT_PEM).is_none());
+}
+
+#[test]
+fn test_x509_path_revocation() {
+    let root = X509Certificate::from_pem(ROOT_PEM).unwrap();
+    let leaf = X509Certificate::from_pem(LEAF_PEM).unwrap();
+    let revoked = X509Crl::from_pem(REVOKED_CRL_PEM).unwrap();
+    let empty = X509Crl::from_pem(EMPTY_CRL_PEM).unwrap();
+    let mut store = X509TrustStore::new();
+    store.add(root.clone());
+
+    // 不提供CRL时不检查吊销状态
+    assert!(X509PathValidator::new(&store, NOW).verify(&leaf).is_ok());
+
+    let crls = [empty.clone()];
+    assert!(X509PathValidator::new(&store, NOW).crls(&crls).verify(&leaf).is_ok());
+
+    let crls = [empty.clone(), revoked.clone()];
+    let error = X509PathValidator::new(&store, NOW).crls(&crls).verify(&leaf).unwrap_err();
+    assert_eq!(error.kind, X509PathErrorKind::Revoked(Some(X509CrlReason::KeyCompromise)));
+    assert_eq!(error.depth, 0);
+    assert_eq!(error.subject, *leaf.subject());
+    assert!(error.to_string().contains("keyCompromise"));
+
+    // 验证时刻早于 thisUpdate，CRL不可用
+    let error = X509PathValidator::new(&store, THIS_UPDATE - 1).crls(&crls).verify(&leaf).unwrap_err();
+    assert_eq!((error.kind, error.depth), (X509PathErrorKind::RevocationUnknown, 0));
+
+    // 签名无效的CRL被忽略
+    let mut der = revoked.as_der().to_vec();
+    let last = der.len() - 1;
+    der[last] ^= 1;
+    let crls = [X509Crl::from_der(&der).unwrap()];
+    let error = X509PathValidator::new(&store, NOW).crls(&crls).verify(&leaf).unwrap_err();
+    assert_eq!(error.kind, X509PathErrorKind::RevocationUnknown);
+}
+
+#[test]
+fn test_x509_path_revocation_partial_crls() {
+    // 根CA -> 中间CA -> 终端证书，只有根CA发布了CRL
+    let root = X509Certificate::from_pem(ROOT_PEM).unwrap();
+    let ca = X509Certificate::from_pem(INTERMEDIATE_PEM).unwrap();
+    let ca_key = Sm2PrivateKey::from_hex(INTERMEDIATE_KEY_HEX).unwrap();
+    assert_eq!(ca_key.public_key(), ca.public_key());
+    // 与根CRL中被吊销的证书序列号相同，但签发者不同
+    let leaf_name = X509Name::from_attributes(&[("CN", "leaf")]).unwrap();
+    let leaf = X509CertificateBuilder::new(leaf_name, Sm2PrivateKey::generate().public_key())
+        .serial_number(&hex_to_bytes("1234567890ABCDEF"))
+        .validity(THIS_UPDATE, NEXT_UPDATE)
+        .extension(X509Extension::basic_constraints(X509BasicConstraints { ca: false, path_len: None }))
+        .extension(X509Extension::key_usage(X509KeyUsage::DIGITAL_SIGNATURE).unwrap())
+        .sign(&ca, &ca_key)
+        .unwrap();
+
+    let mut store = X509TrustStore::new();
+    store.add(root);
+    let intermediates = [ca.clone()];
+    let crls = [X509Crl::from_pem(REVOKED_CRL_PEM).unwrap()];
+
+    // 中间CA由根CRL检查，终端证书的签发者没有发布CRL，不检查
+    let validator = X509PathValidator::new(&store, NOW).intermediates(&intermediates).crls(&crls);
+    assert_eq!(validator.verify(&leaf).unwrap().len(), 3);
+
+    // 严格模式要求每一级都有签发者发布的CRL
+    let error = validator.require_crls().verify(&leaf).unwrap_err();
+    assert_eq!((error.kind, error.depth), (X509PathErrorKind::RevocationUnknown, 0));
+    let error = X509PathValidator::new(&store, NOW).intermediates(&intermediates).require_crls().verify(&leaf);
+    assert_eq!(error.unwrap_err().kind, X509PathErrorKind::RevocationUnknown);
+
+    // 根CA名下的CRL签名无效时，中间CA的状态无法确定
+    let mut der = crls[0].as_der().to_vec();
+    let last = der.len() - 1;
+    der[last] ^= 1;
+    let crls = [X509Crl::from_der(&der).unwrap()];
+    let validator = X509PathValidator::new(&store, NOW).intermediates(&intermediates).crls(&crls);
+    let error = validator.verify(&leaf).unwrap_err();
+    assert_eq!((error.kind, error.depth), (X509PathErrorKind::RevocationUnknown, 1));
+    assert_eq!(error.subject, *ca.subject());
+}
@@ -0,0 +1,237 @@
+// X.509证书路径构建与验证测试
+
+use gm_sdk::{
+    Sm2PrivateKey, X509BasicConstraints, X509Certificate, X509CertificateBuilder, X509Extension, X509KeyUsage,
+    X509Name, X509PathErrorKind, X509PathValidator, X509TrustStore,
+};
+
+// 2030-01-01 00:00:00 UTC 与 2060-01-01 00:00:00 UTC
+const NOT_BEFORE: i64 = 1893456000;
+const NOT_AFTER: i64 = 2840140800;
+const NOW: i64 = 2000000000;
+
+fn name(cn: &str) -> X509Name {
+    X509Name::from_attributes(&[("C", "CN"), ("CN", cn)]).unwrap()
+}
+
+fn ca_builder(cn: &str, key: &Sm2PrivateKey, path_len: Option<u32>) -> X509CertificateBuilder {
+    X509CertificateBuilder::new(name(cn), key.public_key())
+        .validity(NOT_BEFORE, NOT_AFTER)
+        .extension(X509Extension::basic_constraints(X509BasicConstraints { ca: true, path_len }))
+        .extension(X509Extension::key_usage(X509KeyUsage::KEY_CERT_SIGN | X509KeyUsage::CRL_SIGN).unwrap())
+}
+
+fn leaf_builder(key: &Sm2PrivateKey) -> X509CertificateBuilder {
+    X509CertificateBuilder::new(name("leaf"), key.public_key())
+        .validity(NOT_BEFORE, NOT_AFTER)
+        .extension(X509Extension::key_usage(X509KeyUsage::DIGITAL_SIGNATURE).unwrap())
+}
+
+/// 根CA -> 中间CA -> 终端证书
+struct Pki {
+    root: X509Certificate,
+    intermediate: X509Certificate,
+    intermediate_key: Sm2PrivateKey,
+    leaf: X509Certificate,
+    store: X509TrustStore,
+}
+
+fn pki() -> Pki {
+    let root_key = Sm2PrivateKey::generate();
+    let root = ca_builder("Root", &root_key, Some(1)).self_signed(&root_key).unwrap();
+    let intermediate_key = Sm2PrivateKey::generate();
+    let intermediate = ca_builder("Intermediate", &intermediate_key, None).sign(&root, &root_key).unwrap();
+    let leaf = leaf_builder(&Sm2PrivateKey::generate()).sign(&intermediate, &intermediate_key).unwrap();
+    let mut store = X509TrustStore::new();
+    store.add(root.clone());
+    Pki { root, intermediate, intermediate_key, leaf, store }
+}
+
+#[test]
+fn test_x509_path_valid() {
+    let pki = pki();
+    let unrelated_key = Sm2PrivateKey::generate();
+    let unrelated = ca_builder("Other", &unrelated_key, None).self_signed(&unrelated_key).unwrap();
+    let intermediates = [unrelated, pki.intermediate.clone()];
+
+    let path = X509PathValidator::new(&pki.store, NOW).intermediates(&intermediates).verify(&pki.leaf).unwrap();
+    assert_eq!(path, vec![pki.leaf.clone(), pki.intermediate.clone(), pki.root.clone()]);
+
+    // 终端证书用途满足要求
+    let validator = X509PathValidator::new(&pki.store, NOW)
+        .intermediates(&intermediates)
+        .key_usage(X509KeyUsage::DIGITAL_SIGNATURE);
+    assert!(validator.verify(&pki.leaf).is_ok());
+
+    // 信任锚本身
+    assert_eq!(X509PathValidator::new(&pki.store, NOW).verify(&pki.root).unwrap(), vec![pki.root.clone()]);
+}
+
+#[test]
+fn test_x509_path_issuer_not_found() {
+    let pki = pki();
+    let error = X509PathValidator::new(&pki.store, NOW).verify(&pki.leaf).unwrap_err();
+    assert_eq!(error.kind, X509PathErrorKind::IssuerNotFound);
+    assert_eq!(error.depth, 0);
+    assert_eq!(error.subject, name("leaf"));
+
+    // 根证书不在信任锚中
+    let intermediates = [pki.intermediate.clone(), pki.root.clone()];
+    let error = X509PathValidator::new(&X509TrustStore::new(), NOW).intermediates(&intermediates).verify(&pki.leaf);
+    let error = error.unwrap_err();
+    assert_eq!(error.kind, X509PathErrorKind::NotTrusted);
+    assert_eq!(error.depth, 2);
+    assert_eq!(error.subject, name("Root"));
+}
+
+#[test]
+fn test_x509_path_signature() {
+    let pki = pki();
+    // 与中间CA同名、同密钥标识但密钥不同的证书签发的终端证书
+    let fake_key = Sm2PrivateKey::generate();
+    let ski = pki.intermediate.subject_key_identifier().unwrap();
+    let fake = ca_builder("Intermediate", &fake_key, None)
+        .extension(X509Extension::subject_key_identifier(&ski))
+        .self_signed(&fake_key)
+        .unwrap();
+    let leaf = leaf_builder(&Sm2PrivateKey::generate()).sign(&fake, &fake_key).unwrap();
+
+    let intermediates = [pki.intermediate.clone()];
+    let error = X509PathValidator::new(&pki.store, NOW).intermediates(&intermediates).verify(&leaf).unwrap_err();
+    assert_eq!(error.kind, X509PathErrorKind::Signature);
+    assert_eq!(error.depth, 0);
+}
+
+#[test]
+fn test_x509_path_validity() {
+    let pki = pki();
+    let intermediates = [pki.intermediate.clone()];
+
+    let error = X509PathValidator::new(&pki.store, NOT_AFTER + 1).intermediates(&intermediates).verify(&pki.leaf);
+    assert_eq!(error.unwrap_err().kind, X509PathErrorKind::Expired);
+    let error = X509PathValidator::new(&pki.store, NOT_BEFORE - 1).intermediates(&intermediates).verify(&pki.leaf);
+    assert_eq!(error.unwrap_err().kind, X509PathErrorKind::NotYetValid);
+
+    // 有效期边界包含在内
+    let validator = X509PathValidator::new(&pki.store, NOT_AFTER).intermediates(&intermediates);
+    assert!(validator.verify(&pki.leaf).is_ok());
+
+    // 中间CA先于终端证书过期
+    let root_key = Sm2PrivateKey::generate();
+    let root = ca_builder("Root", &root_key, None).self_signed(&root_key).unwrap();
+    let ca_key = Sm2PrivateKey::generate();
+    let ca = ca_builder("Intermediate", &ca_key, None).validity(NOT_BEFORE, NOW - 1).sign(&root, &root_key).unwrap();
+    let leaf = leaf_builder(&Sm2PrivateKey::generate()).sign(&ca, &ca_key).unwrap();
+    let mut store = X509TrustStore::new();
+    store.add(root);
+    let intermediates = [ca];
+    let error = X509PathValidator::new(&store, NOW).intermediates(&intermediates).verify(&leaf).unwrap_err();
+    assert_eq!(error.kind, X509PathErrorKind::Expired);
+    assert_eq!(error.depth, 1);
+    assert_eq!(error.subject, name("Intermediate"));
+}
+
+#[test]
+fn test_x509_path_constraints() {
+    let root_key = Sm2PrivateKey::generate();
+    let root = ca_builder("Root", &root_key, Some(0)).self_signed(&root_key).unwrap();
+    let mut store = X509TrustStore::new();
+    store.add(root.clone());
+
+    // 根CA的pathLen为0，其下不能再有中间CA
+    let ca_key = Sm2PrivateKey::generate();
+    let ca = ca_builder("Intermediate", &ca_key, None).sign(&root, &root_key).unwrap();
+    let leaf = leaf_builder(&Sm2PrivateKey::generate()).sign(&ca, &ca_key).unwrap();
+    let intermediates = [ca];
+    let error = X509PathValidator::new(&store, NOW).intermediates(&intermediates).verify(&leaf).unwrap_err();
+    assert_eq!(error.kind, X509PathErrorKind::PathLenExceeded);
+    assert_eq!(error.depth, 2);
+
+    // 直接由根CA签发不受影响
+    let leaf = leaf_builder(&Sm2PrivateKey::generate()).sign(&root, &root_key).unwrap();
+    assert!(X509PathValidator::new(&store, NOW).verify(&leaf).is_ok());
+
+    // 非CA证书签发的证书
+    let not_ca = leaf_builder(&ca_key)
+        .extension(X509Extension::basic_constraints(X509BasicConstraints { ca: false, path_len: None }))
+        .extension(X509Extension::key_usage(X509KeyUsage::KEY_CERT_SIGN).unwrap())
+        .sign(&root, &root_key)
+        .unwrap();
+    let leaf = X509CertificateBuilder::new(name("end"), Sm2PrivateKey::generate().public_key())
+        .validity(NOT_BEFORE, NOT_AFTER)
+        .sign(&not_ca, &ca_key)
+        .unwrap();
+    let intermediates = [not_ca];
+    let error = X509PathValidator::new(&store, NOW).intermediates(&intermediates).verify(&leaf).unwrap_err();
+    assert_eq!(error.kind, X509PathErrorKind::NotCa);
+    assert_eq!(error.depth, 1);
+    assert!(error.to_string().contains("签发者不是CA证书"));
+}
+
+#[test]
+fn test_x509_path_key_usage() {
+    let pki = pki();
+    let intermediates = [pki.intermediate.clone()];
+
+    // 终端证书不满足要求的用途
+    let validator = X509PathValidator::new(&pki.store, NOW)
+        .intermediates(&intermediates)
+        .key_usage(X509KeyUsage::KEY_ENCIPHERMENT);
+    let error = validator.verify(&pki.leaf).unwrap_err();
+    assert_eq!((error.kind, error.depth), (X509PathErrorKind::KeyUsage, 0));
+
+    // 中间CA的keyUsage不含keyCertSign
+    let root_key = Sm2PrivateKey::generate();
+    let root = ca_builder("Root", &root_key, None).self_signed(&root_key).unwrap();
+    let ca_key = Sm2PrivateKey::generate();
+    let ca = ca_builder("Intermediate", &ca_key, None)
+        .extension(X509Extension::key_usage(X509KeyUsage::DIGITAL_SIGNATURE).unwrap())
+        .sign(&root, &root_key)
+        .unwrap();
+    let leaf = leaf_builder(&Sm2PrivateKey::generate()).sign(&ca, &ca_key).unwrap();
+    let mut store = X509TrustStore::new();
+    store.add(root);
+    let intermediates = [ca];
+    let error = X509PathValidator::new(&store, NOW).intermediates(&intermediates).verify(&leaf).unwrap_err();
+    assert_eq!((error.kind, error.depth), (X509PathErrorKind::KeyUsage, 1));
+}
+
+#[test]
+fn test_x509_path_critical_extension() {
+    let pki = pki();
+    let extension = X509Extension { oid: "1.2.3.4".to_string(), critical: true, value: vec![0x05, 0x00] };
+    let leaf = leaf_builder(&Sm2PrivateKey::generate())
+        .extension(extension)
+        .sign(&pki.intermediate, &pki.intermediate_key)
+        .unwrap();
+    let intermediates = [pki.intermediate.clone()];
+    let error = X509PathValidator::new(&pki.store, NOW).intermediates(&intermediates).verify(&leaf).unwrap_err();
+    assert_eq!((error.kind, error.depth), (X509PathErrorKind::UnsupportedCriticalExtension, 0));
+}
+
+#[test]
+fn test_x509_trust_store_load_dir() {
+    let pki = pki();
+    let dir = std::env::temp_dir().join(format!("gm_sdk_trust_store_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let bundle = format!("{}{}", pki.root.to_pem(), pki.intermediate.to_pem());
+    std::fs::write(dir.join("bundle.pem"), bundle).unwrap();
+    std::fs::write(dir.join("root.CRT"), pki.root.to_pem()).unwrap();
+    std::fs::write(dir.join("notes.txt"), "not a certificate").unwrap();
+
+    let mut store = X509TrustStore::new();
+    assert_eq!(store.load_dir(&dir).unwrap(), 2);
+    assert_eq!(store.len(), 2);
+    assert!(store.contains(&pki.root));
+    assert!(store.contains(&pki.intermediate));
+
+    // 排在最后的文件含有非法证书：其余文件中的证书也不加入
+    std::fs::write(dir.join("zz_broken.cer"), "-----BEGIN CERTIFICATE-----\nAAAA\n-----END CERTIFICATE-----\n").unwrap();
+    let mut store = X509TrustStore::new();
+    let error = store.load_dir(&dir).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(store.is_empty());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(X509TrustStore::new().load_dir(&dir).is_err());
+}